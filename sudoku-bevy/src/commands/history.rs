@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::{
+    create_game_command,
+    plugins::game_commands::GameCommand,
+    shared::resources::{
+        ActiveBoardProvider, BoardPlayingState, BoardsStateMap, SudokuBoardResources,
+    },
+};
+
+create_game_command!(UndoCommand, undo);
+create_game_command!(RedoCommand, redo);
+
+fn undo(
+    active_board: ActiveBoardProvider,
+    boards: ResMut<SudokuBoardResources>,
+    boards_state: ResMut<BoardsStateMap>,
+) {
+    step_history(active_board, boards, boards_state, true);
+}
+
+fn redo(
+    active_board: ActiveBoardProvider,
+    boards: ResMut<SudokuBoardResources>,
+    boards_state: ResMut<BoardsStateMap>,
+) {
+    step_history(active_board, boards, boards_state, false);
+}
+
+fn step_history(
+    active_board: ActiveBoardProvider,
+    mut boards: ResMut<SudokuBoardResources>,
+    mut boards_state: ResMut<BoardsStateMap>,
+    backward: bool,
+) {
+    let active_board = if let Some(active_board) = active_board.active_board() {
+        active_board
+    } else {
+        return;
+    };
+
+    let board = boards.active_board_mut(active_board);
+    let Some(state) = boards_state.get_mut(active_board) else {
+        return;
+    };
+
+    if matches!(state.playing_state, BoardPlayingState::FinishedVerified) {
+        // Board in finished state do nothing.
+        return;
+    }
+
+    let changed = if backward {
+        #[cfg(feature = "debug")]
+        println!("Undoing.");
+        state.history.undo(board)
+    } else {
+        #[cfg(feature = "debug")]
+        println!("Redoing.");
+        state.history.redo(board)
+    };
+
+    if changed {
+        board.mark_all_conflicts();
+    }
+}
@@ -34,6 +34,7 @@ fn reset_board(
     if let Some(state) = board_state {
         state.stats = Default::default();
         state.playing_state = BoardPlayingState::Playing;
+        state.history.clear();
     }
 
     help_text.0 = DEFAULT_HELP_TEXT.to_string();
@@ -26,7 +26,10 @@ use bevy_tweening::{
 };
 use sudoku_bevy::{
     BlocksAccessInfo, SquareIndex,
-    commands::reset_board::ResetBoardCommand,
+    commands::{
+        history::{RedoCommand, UndoCommand},
+        reset_board::ResetBoardCommand,
+    },
     create_game_command,
     extensions::CustomEntityCommands,
     gen_random_city_name,
@@ -43,8 +46,10 @@ use sudoku_bevy::{
 use sudoku_solver::{
     BlockIndex, Conflicting, Possibilities as SudokuPossibilities, SudokuBlock, SudokuBlockStatus,
     SudokuBoard,
+    difficulty::rate,
+    generator::generate,
     numbers::{SudokuNumber, SudokuNumbers},
-    strategies::{Strategy, hidden_single::HiddenSingleStrategy, naked_pair::NakedPairStrategy},
+    strategies::{Strategy, coloring::CandidateColor, pipeline::StrategyRegistry},
 };
 
 #[derive(Debug, Default)]
@@ -104,6 +109,13 @@ struct EngagingStrategyMap {
     engaging: HashMap<BoardId, EngagingStrategy>,
 }
 
+#[derive(Debug, Resource, Default, Deref)]
+struct StrategyRegistryResource(StrategyRegistry);
+
+/// A page of the strategies bar, only one of them is visible at a time.
+#[derive(Debug, Component)]
+struct StrategyBarPage(usize);
+
 #[derive(Debug, Event)]
 struct UpdateBoardList;
 
@@ -129,6 +141,7 @@ fn main() {
         .init_resource::<SudokuBoardSnapshotResources>()
         .init_resource::<SelectedBlock>()
         .init_resource::<EngagingStrategyMap>()
+        .init_resource::<StrategyRegistryResource>()
         .init_resource::<BoardsStateMap>()
         .add_message::<ShouldUpdateEvent>()
         .init_resource::<StatsTextEntities>()
@@ -161,7 +174,11 @@ fn main() {
             OnEnter(AppState::Ready),
             (setup_game, check_foundation_squares, check_block_squares).chain(),
         )
-        // .add_systems(Update, )
+        .add_systems(
+            Update,
+            next_strategy_bar_page
+                .run_if(input_just_pressed(KeyCode::Tab).and(in_state(AppState::Ready))),
+        )
         .add_systems(
             PostUpdate,
             (
@@ -217,24 +234,45 @@ fn setup_game(
     log::warn!("Death is close.");
 
     commands.register_game_command::<ResetBoardCommand>();
+    commands.register_game_command::<UndoCommand>();
+    commands.register_game_command::<RedoCommand>();
     commands.register_game_command::<EngageStrategy>();
     commands.register_game_command::<ChangeDirection>();
     commands.register_game_command::<ChangeSelectionMode>();
     commands.register_game_command::<InputNumber>();
 
-    let boards = [
-        (None, vec![SudokuBoard::default()]),
-        (
-            Some(SudokuBoardDifficulty::Easy),
-            vec![SudokuBoard::from_u8(sudoku_samples::easy::FIRST)],
-        ),
-        (
-            Some(SudokuBoardDifficulty::Normal),
-            vec![SudokuBoard::from_u8(sudoku_samples::normal::FIRST)],
-        ),
-    ];
+    // Boards are labeled by rating them, not by where they came from.
+    let mut boards: HashMap<Option<SudokuBoardDifficulty>, Vec<SudokuBoard>> =
+        HashMap::from([(None, vec![SudokuBoard::default()])]);
+
+    for numbers in [
+        sudoku_samples::easy::FIRST,
+        sudoku_samples::normal::FIRST,
+        generate(&mut rand::rng(), SudokuBoardDifficulty::Normal),
+        generate(&mut rand::rng(), SudokuBoardDifficulty::Hard),
+        generate(&mut rand::rng(), SudokuBoardDifficulty::Expert),
+    ] {
+        let board = SudokuBoard::from_u8(numbers);
+        boards
+            .entry(Some(rate(&board).difficulty))
+            .or_default()
+            .push(board);
+    }
+
+    for (k, mut v) in boards {
+        if let Some(difficulty) = k {
+            for (i, board) in v.iter_mut().enumerate() {
+                // Entries are checked against the solution, when there's only one.
+                if !board.remember_solution() {
+                    log::warn!(
+                        "Board #{} {} doesn't have a unique solution.",
+                        i + 1,
+                        difficulty
+                    );
+                }
+            }
+        }
 
-    for (k, v) in boards {
         let boards_count = v.len();
         sudoku_boards.insert(k, v);
         sudoku_snapshots.insert(k, (0..boards_count).map(|_| Default::default()).collect());
@@ -392,35 +430,45 @@ fn setup_game(
         });
 
     commands.spawn(TextBundle::new(
-        "Strategies",
+        "Strategies (Tab)",
         defaults_assets.default_font.clone(),
         20.,
         ORANGE_300,
         Transform::from_translation(Vec3::default().with_xy(vec2(420., -60.))),
     ));
 
-    // Strategies
+    // Strategies, nine on each page.
     let spawn_info = SquareSpawnInfo {
         width: 180.,
         translation: vec2(420., -165.),
         index: (0, 0),
     };
 
-    commands
-        .spawn((SquareBundle::new(
-            defaults.default_foundation_block_color.clone(),
-            &mut meshes,
-            spawn_info.clone(),
-            None,
-        ),))
-        .with_children(|builder| {
-            let width = spawn_info.width;
-            let master_index = spawn_info.index;
-            let strategies = [Strategy::HiddenSingle, Strategy::NakedPair];
-
-            for (index, spawn_info) in square_group_info(width, 5., Default::default()).enumerate()
-            {
-                if let Some(strategy) = strategies.get(index) {
+    for (page, strategies) in Strategy::ALL.chunks(9).enumerate() {
+        commands
+            .spawn((
+                SquareBundle::new(
+                    defaults.default_foundation_block_color.clone(),
+                    &mut meshes,
+                    spawn_info.clone(),
+                    None,
+                ),
+                StrategyBarPage(page),
+                if page == 0 {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                },
+            ))
+            .with_children(|builder| {
+                let width = spawn_info.width;
+                let master_index = spawn_info.index;
+
+                for (strategy, spawn_info) in
+                    strategies
+                        .iter()
+                        .zip(square_group_info(width, 5., Default::default()))
+                {
                     builder
                         .spawn((
                             SquareBundle::new(
@@ -451,8 +499,8 @@ fn setup_game(
                         .observe(on_helper_block_hovered)
                         .observe(on_pointer_out);
                 }
-            }
-        });
+            });
+    }
 
     commands.spawn((
         TextBundle::new_with_layout(
@@ -1218,6 +1266,15 @@ fn update_board(
                                             {
                                                 if strategy.is_effected() {
                                                     defaults.strategy_effected_color.clone()
+                                                } else if let Some(color) = strategy.color() {
+                                                    match color {
+                                                        CandidateColor::First => {
+                                                            defaults.coloring_first_color.clone()
+                                                        }
+                                                        CandidateColor::Second => {
+                                                            defaults.coloring_second_color.clone()
+                                                        }
+                                                    }
                                                 } else if let Some(color) =
                                                     strategy_colors.get(&strategy.strategy())
                                                 {
@@ -1279,6 +1336,9 @@ fn update_board(
                     Some(sudoku_solver::Conflicting::AffectedByPossibilities { .. }) => {
                         material.0 = defaults.conflicting_affected_color.clone();
                     }
+                    Some(sudoku_solver::Conflicting::Mistake) => {
+                        material.0 = defaults.conflicting_mistake_color.clone();
+                    }
                     _ => {}
                 }
             }
@@ -1440,7 +1500,7 @@ fn on_game_input(
     mut commands: Commands,
     active_board: ActiveBoardProvider,
     mut boards: ResMut<SudokuBoardResources>,
-    boards_state: Res<BoardsStateMap>,
+    mut boards_state: ResMut<BoardsStateMap>,
     selected: Res<SelectedBlock>,
 ) {
     let active_board = if let Some(active_board) = active_board.active_board() {
@@ -1466,7 +1526,20 @@ fn on_game_input(
 
             #[cfg(feature = "debug")]
             println!("Updating possibilities.");
-            board.update_possibilities();
+            boards_state.record(active_board, board, |board| board.update_possibilities());
+        }
+        CommandType::RecomputePossibilities => {
+            let board = boards.active_board_mut(active_board);
+            if board_state
+                .is_some_and(|f| matches!(f.playing_state, BoardPlayingState::FinishedVerified))
+            {
+                // Board in finished state do nothing.
+                return;
+            }
+
+            #[cfg(feature = "debug")]
+            println!("Recomputing possibilities.");
+            boards_state.record(active_board, board, |board| board.recompute_possibilities());
         }
         CommandType::ResolveNakedSingles => {
             let board = boards.active_board_mut(active_board);
@@ -1479,7 +1552,9 @@ fn on_game_input(
 
             #[cfg(feature = "debug")]
             println!("Resolving satisfied blocks (Naked single).");
-            board.resolve_satisfied_blocks();
+            boards_state.record(active_board, board, |board| {
+                board.resolve_satisfied_blocks()
+            });
         }
         CommandType::Reset => {
             commands.trigger_game_command::<ResetBoardCommand>();
@@ -1498,14 +1573,13 @@ fn on_game_input(
 
             let block_index =
                 BlockIndex::from_index(selected.current.1, selected.current.0).unwrap();
-            let block = board.get_block_mut(&block_index);
 
-            match &block.status {
-                SudokuBlockStatus::Fixed(_) => (),
-                _ => {
-                    block.status = SudokuBlockStatus::Unresolved;
-                    board.mark_conflicts(&block_index, None);
-                }
+            if !board.get_block(&block_index).is_fixed() {
+                // Also gives a resolved number back to the peers.
+                boards_state.record(active_board, board, |board| {
+                    board.unplace(&block_index);
+                });
+                board.mark_conflicts(&block_index, None);
             }
         }
         CommandType::Direction(direction) => {
@@ -1530,11 +1604,10 @@ fn _input_number(
         return;
     };
 
-    let board_state = boards_state.get_mut(active_board);
     let board = boards.active_board_mut(active_board);
 
-    if board_state
-        .as_ref()
+    if boards_state
+        .get(active_board)
         .is_some_and(|f| matches!(f.playing_state, BoardPlayingState::FinishedVerified))
     {
         // Board in finished state do nothing.
@@ -1542,12 +1615,13 @@ fn _input_number(
     }
 
     let block_index = BlockIndex::from_index(selected.current.1, selected.current.0).unwrap();
-    let block = board.get_block_mut(&block_index);
-
-    match &block.status {
+    match &board.get_block(&block_index).status {
         SudokuBlockStatus::Fixed(_) => (),
         _ => {
-            let update_result = Some(_update_block(&selected, block, sudoku_number));
+            let update_result = Some(boards_state.record(active_board, board, |board| {
+                _update_block(&selected, board, &block_index, sudoku_number)
+            }));
+            let board_state = boards_state.get_mut(active_board);
 
             if let Some(result) = update_result {
                 match result {
@@ -1557,12 +1631,19 @@ fn _input_number(
                     BlockUpdateResult::Resolved => {
                         board.mark_conflicts(&block_index, None);
 
-                        let block = board.get_block(&block_index);
-                        if block
-                            .conflicting
-                            .as_ref()
-                            .is_some_and(|f| matches!(f, Conflicting::Source))
-                        {
+                        // Compare with the solution if it's known, a right number may
+                        // still repeat a wrong one.
+                        let is_mistake = if board.has_solution() {
+                            board.is_mistake(&block_index)
+                        } else {
+                            board
+                                .get_block(&block_index)
+                                .conflicting
+                                .as_ref()
+                                .is_some_and(|f| matches!(f, Conflicting::Source))
+                        };
+
+                        if is_mistake {
                             // This is a mistake!
                             if let Some(state) = board_state {
                                 state.stats.mistakes += 1;
@@ -1577,7 +1658,13 @@ fn _input_number(
                         let block = board.get_block(&block_index);
                         let poss = block.status.as_possibilities().unwrap(); // This must be possibilities
 
-                        if poss.is_conflicting(number) {
+                        let is_mistake = if board.has_solution() {
+                            is_cleared && board.solution_number(&block_index) == Some(number)
+                        } else {
+                            poss.is_conflicting(number)
+                        };
+
+                        if is_mistake {
                             // This is also a mistake
                             if let Some(state) = board_state {
                                 state.stats.possibility_mistakes += 1;
@@ -1661,6 +1748,7 @@ fn _engage_strategy(
     mut boards: ResMut<SudokuBoardResources>,
     mut boards_state: ResMut<BoardsStateMap>,
     mut engaging: ResMut<EngagingStrategyMap>,
+    registry: Res<StrategyRegistryResource>,
 ) {
     let active_board = if let Some(active_board) = active_board.active_board() {
         active_board
@@ -1669,9 +1757,11 @@ fn _engage_strategy(
     };
 
     let board = boards.active_board_mut(active_board);
-    let board_state = boards_state.get_mut(active_board);
 
-    if board_state.is_some_and(|f| matches!(f.playing_state, BoardPlayingState::FinishedVerified)) {
+    if boards_state
+        .get(active_board)
+        .is_some_and(|f| matches!(f.playing_state, BoardPlayingState::FinishedVerified))
+    {
         // Board in finished state do nothing.
         return;
     }
@@ -1700,23 +1790,46 @@ fn _engage_strategy(
         engaging.strategy = Some(strategy);
     }
 
-    match strategy {
-        Strategy::HiddenSingle => {
-            #[cfg(feature = "debug")]
-            println!("Engaging Hidden single Strategy.");
-            board.engage_strategy(HiddenSingleStrategy, show_only_effect);
+    if let Some(solver) = registry.get(strategy) {
+        if solver.requires_uniqueness() && !board.is_uniquely_solvable() {
+            log::warn!("{} needs a board with a unique solution.", strategy.name());
+            return;
         }
-        Strategy::NakedPair => {
-            #[cfg(feature = "debug")]
-            println!("Engaging Naked pair Strategy.");
-            board.engage_strategy(NakedPairStrategy, show_only_effect);
+
+        #[cfg(feature = "debug")]
+        println!("Engaging {} Strategy.", strategy.name());
+        if show_only_effect {
+            board.engage_strategy(solver.as_ref(), show_only_effect);
+        } else {
+            // Only applying the strategy can be undone, not showing its effect.
+            boards_state.record(active_board, board, |board| {
+                board.engage_strategy(solver.as_ref(), show_only_effect)
+            });
         }
-        _ => (),
     }
 }
 
 create_game_command!(EngageStrategy, Strategy, _engage_strategy);
 
+fn next_strategy_bar_page(mut pages: Query<(&StrategyBarPage, &mut Visibility)>) {
+    let count = pages.iter().count();
+    let Some(current) = pages
+        .iter()
+        .find(|(_, visibility)| **visibility != Visibility::Hidden)
+        .map(|(page, _)| page.0)
+    else {
+        return;
+    };
+
+    for (page, mut visibility) in &mut pages {
+        *visibility = if page.0 == (current + 1) % count {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
 fn on_helper_block_hovered(
     over: On<Pointer<Over>>,
     indexes: Query<&HelperBlock>,
@@ -1728,7 +1841,12 @@ fn on_helper_block_hovered(
                 format!("Puts number {} in selected block.", (sudoku_number.to_u8()))
             }
             CommandType::CalculatePossibilities => {
-                "Updates possible values based on currently filled blocks.".to_string()
+                "Removes possible values ruled out by filled blocks, keeping the ones you removed."
+                    .to_string()
+            }
+            CommandType::RecomputePossibilities => {
+                "Recomputes every possible value from scratch, based on currently filled blocks."
+                    .to_string()
             }
             CommandType::ResolveNakedSingles => {
                 "Resolve blocks that are naked singles (Blocks with only one possible number)."
@@ -1751,14 +1869,7 @@ fn on_helper_block_hovered(
                 )
             }
             CommandType::Strategy(strategy) => {
-                format!(
-                    "Applying strategy: {}",
-                    match strategy {
-                        Strategy::HiddenSingle => "Hidden singles.",
-                        Strategy::NakedSingle => "Naked single",
-                        Strategy::NakedPair => "Naked pair",
-                    }
-                )
+                format!("Applying strategy: {}", strategy.name())
             }
         };
 
@@ -2006,27 +2117,32 @@ enum BlockUpdateResult {
 
 fn _update_block(
     selected: &SelectedBlock,
-    block: &mut sudoku_solver::SudokuBlock,
+    board: &mut SudokuBoard,
+    block_index: &BlockIndex,
     number: SudokuNumber,
 ) -> BlockUpdateResult {
     use BlockUpdateResult::*;
 
+    let block = board.get_block_mut(block_index);
     match selected.mode {
         SelectionMode::Resolving => {
-            if let SudokuBlockStatus::Resolved(already) = block.status
-                && already == number
-            {
-                block.status = SudokuBlockStatus::Unresolved;
-                return Cleared;
+            if let SudokuBlockStatus::Resolved(already) = block.status {
+                // Gives the previous number back to the peers first.
+                board.unplace(block_index);
+                if already == number {
+                    return Cleared;
+                }
             }
 
-            block.status = SudokuBlockStatus::Resolved(number);
+            // Also removes the number from possible values of the peers.
+            board.place(block_index, number);
             Resolved
         }
         SelectionMode::Possibilities => {
             if let Some(pos) = block.status.as_possibilities_mut() {
                 if pos.numbers.has_number(number) {
-                    pos.numbers.del_number(number);
+                    // Remembered, so updating possibilities doesn't add it back.
+                    pos.remove_number(number);
 
                     if pos.numbers.count_numbers() == 0 {
                         block.status = SudokuBlockStatus::Unresolved;
@@ -2038,7 +2154,7 @@ fn _update_block(
                         }
                     }
                 } else {
-                    pos.numbers.set_number(number);
+                    pos.add_number(number);
                     Possible {
                         number,
                         is_cleared: false,
@@ -7,7 +7,10 @@ use bevy::{
 use sudoku_solver::{numbers::SudokuNumber, strategies::Strategy};
 
 use crate::{
-    commands::reset_board::ResetBoardCommand,
+    commands::{
+        history::{RedoCommand, UndoCommand},
+        reset_board::ResetBoardCommand,
+    },
     plugins::{
         game_commands::GameCommandsExtensions,
         shared::{AppState, CommandType, Direction, GameInputs},
@@ -37,11 +40,16 @@ impl Plugin for InputHandlingPlugin {
                 change_selection_mode.run_if(input_just_pressed(KeyCode::KeyM)),
                 (
                     engage_strategy.run_if(
-                        input_just_pressed(KeyCode::KeyH).or(input_just_pressed(KeyCode::KeyP)),
+                        input_just_pressed(KeyCode::KeyS)
+                            .or(input_just_pressed(KeyCode::KeyH))
+                            .or(input_just_pressed(KeyCode::KeyL))
+                            .or(input_just_pressed(KeyCode::KeyP)),
                     ),
                     update_possibilities.run_if(input_just_pressed(KeyCode::Space)),
                     resolve_satisfied.run_if(input_just_pressed(KeyCode::Enter)),
                     manually_clear_block.run_if(input_just_pressed(KeyCode::KeyC)),
+                    undo.run_if(input_just_pressed(KeyCode::KeyZ)),
+                    redo.run_if(input_just_pressed(KeyCode::KeyY)),
                     digit_1_to_9_clicked.run_if(
                         input_just_pressed(KeyCode::Digit1)
                             .or(input_just_pressed(KeyCode::Digit2))
@@ -70,17 +78,33 @@ impl Plugin for InputHandlingPlugin {
     }
 }
 
-fn update_possibilities(mut commands: Commands) {
-    commands.trigger(GameInputs::new(CommandType::CalculatePossibilities));
+fn update_possibilities(mut commands: Commands, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        commands.trigger(GameInputs::new(CommandType::RecomputePossibilities));
+    } else {
+        commands.trigger(GameInputs::new(CommandType::CalculatePossibilities));
+    }
 }
 
 fn engage_strategy(mut commands: Commands, keyboard_input: Res<ButtonInput<KeyCode>>) {
+    if keyboard_input.just_pressed(KeyCode::KeyS) {
+        commands.trigger(GameInputs::new(CommandType::Strategy(
+            Strategy::NakedSingle,
+        )));
+    }
+
     if keyboard_input.just_pressed(KeyCode::KeyH) {
         commands.trigger(GameInputs::new(CommandType::Strategy(
             Strategy::HiddenSingle,
         )));
     }
 
+    if keyboard_input.just_pressed(KeyCode::KeyL) {
+        commands.trigger(GameInputs::new(CommandType::Strategy(
+            Strategy::LockedCandidates,
+        )));
+    }
+
     if keyboard_input.just_pressed(KeyCode::KeyP) {
         commands.trigger(GameInputs::new(CommandType::Strategy(Strategy::NakedPair)));
     }
@@ -94,6 +118,14 @@ fn reset(mut commands: Commands) {
     commands.trigger_game_command_with::<ResetBoardCommand>(());
 }
 
+fn undo(mut commands: Commands) {
+    commands.trigger_game_command::<UndoCommand>();
+}
+
+fn redo(mut commands: Commands) {
+    commands.trigger_game_command::<RedoCommand>();
+}
+
 fn change_selection_mode(mut commands: Commands) {
     commands.trigger(GameInputs::new(CommandType::ChangeSelectionMode));
 }
@@ -3,7 +3,13 @@ use bevy::{
     color::palettes::{
         basic::PURPLE,
         css::{BLACK, BLUE, RED, WHITE, YELLOW},
-        tailwind::{BLUE_200, GRAY_600, GREEN_400, ORANGE_400, ORANGE_500, RED_400, YELLOW_400},
+        tailwind::{
+            AMBER_400, AMBER_600, BLUE_200, BLUE_400, CYAN_400, CYAN_600, EMERALD_400, EMERALD_600,
+            FUCHSIA_400, FUCHSIA_600, GRAY_600, GREEN_400, GREEN_600, INDIGO_400, INDIGO_600,
+            LIME_300, LIME_400, ORANGE_400, ORANGE_500, ORANGE_600, PINK_400, PINK_600, PURPLE_400,
+            RED_300, RED_400, ROSE_400, ROSE_600, SKY_300, SKY_400, SLATE_400, STONE_400, TEAL_400,
+            TEAL_600, VIOLET_400, VIOLET_600, YELLOW_400, YELLOW_600,
+        },
     },
     platform::collections::HashMap,
     prelude::*,
@@ -28,11 +34,15 @@ pub struct DefaultMaterials {
 
     pub conflicting_source_color: Handle<ColorMaterial>,
     pub conflicting_affected_color: Handle<ColorMaterial>,
+    pub conflicting_mistake_color: Handle<ColorMaterial>,
 
     pub strategy_source_color: Handle<ColorMaterial>,
     pub strategy_effected_color: Handle<ColorMaterial>,
     pub strategy_source_text_color: Color,
 
+    pub coloring_first_color: Handle<ColorMaterial>,
+    pub coloring_second_color: Handle<ColorMaterial>,
+
     // Colors
     pub default_base_text_color: Color,
     pub default_fixed_number_color: Color,
@@ -163,26 +173,170 @@ fn setup_asset_loading(
 
     defaults.conflicting_source_color = materials.add(Color::from(RED));
     defaults.conflicting_affected_color = materials.add(Color::from(RED_400));
+    defaults.conflicting_mistake_color = materials.add(Color::from(RED_300));
 
     defaults.strategy_source_color = materials.add(Color::from(GREEN_400));
     defaults.strategy_effected_color = materials.add(Color::from(BLACK));
     defaults.strategy_source_text_color = Color::from(BLACK);
 
+    defaults.coloring_first_color = materials.add(Color::from(LIME_300));
+    defaults.coloring_second_color = materials.add(Color::from(SKY_300));
+
     defaults.default_base_text_color = Color::from(BLACK);
     defaults.default_fixed_number_color = Color::from(GRAY_600);
     defaults.default_possibility_number_color = Color::from(WHITE);
     defaults.default_resolved_number_color = Color::from(BLACK);
 
+    strategy_colors.insert(
+        Strategy::NakedSingle,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(SKY_400))),
+    );
+
     strategy_colors.insert(
         Strategy::HiddenSingle,
         BlockColorInfo::new(BLACK, materials.add(Color::from(GREEN_400))),
     );
 
+    strategy_colors.insert(
+        Strategy::LockedCandidates,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(TEAL_400))),
+    );
+
     strategy_colors.insert(
         Strategy::NakedPair,
         BlockColorInfo::new(BLACK, materials.add(Color::from(ORANGE_400))),
     );
 
+    strategy_colors.insert(
+        Strategy::NakedTriple,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(AMBER_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::NakedQuad,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(LIME_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::HiddenPair,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(VIOLET_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::HiddenTriple,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(PURPLE_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::HiddenQuad,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(FUCHSIA_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::XWing,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(CYAN_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::Swordfish,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(EMERALD_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::Jellyfish,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(INDIGO_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::XYWing,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(PINK_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::XYZWing,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(ROSE_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::WWing,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(STONE_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::Skyscraper,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(BLUE_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::TwoStringKite,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(GREEN_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::EmptyRectangle,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(SLATE_400))),
+    );
+
+    strategy_colors.insert(
+        Strategy::SimpleColoring,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(YELLOW_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::Medusa,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(RED_300))),
+    );
+
+    strategy_colors.insert(
+        Strategy::XChain,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(EMERALD_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::XYChain,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(VIOLET_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::AlternatingInferenceChain,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(ORANGE_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::UniqueRectangle,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(CYAN_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::BivalueUniversalGrave,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(PINK_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::AlsXz,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(TEAL_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::DeathBlossom,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(FUCHSIA_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::CellForcingChain,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(INDIGO_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::UnitForcingChain,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(ROSE_600))),
+    );
+
+    strategy_colors.insert(
+        Strategy::Nishio,
+        BlockColorInfo::new(BLACK, materials.add(Color::from(AMBER_600))),
+    );
+
     let font = asset_server.load("fonts/FiraSans-Bold.ttf");
     defaults_assets.default_font = font;
 
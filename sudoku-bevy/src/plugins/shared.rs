@@ -32,6 +32,7 @@ impl Display for Direction {
 pub enum CommandType {
     Number(SudokuNumber),
     CalculatePossibilities,
+    RecomputePossibilities,
     ResolveNakedSingles,
     Reset,
     ChangeSelectionMode,
@@ -45,6 +46,7 @@ impl Display for CommandType {
         match self {
             CommandType::Number(sudoku_number) => sudoku_number.to_u8().fmt(f),
             CommandType::CalculatePossibilities => f.write_str("Space"),
+            CommandType::RecomputePossibilities => f.write_str("Shift+Space"),
             CommandType::ResolveNakedSingles => f.write_str("ENTER"),
             CommandType::Reset => f.write_char('R'),
             CommandType::ChangeSelectionMode => f.write_char('M'),
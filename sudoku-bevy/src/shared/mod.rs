@@ -1,30 +1,9 @@
-use std::fmt::Display;
+pub use sudoku_solver::difficulty::SudokuBoardDifficulty;
 
 pub mod components;
 pub mod resources;
 
-pub const DEFAULT_HELP_TEXT: &str = "Use 'Space' to update possible values, 'Enter' to resolve blocks, 'R' to reset, 'M' to change selection mode, 'C' to clear block, 1 to 9 to set number and 'H' to engage Hidden single strategy.";
-
-#[allow(dead_code)]
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum SudokuBoardDifficulty {
-    Easy,
-    #[default]
-    Normal,
-    Hard,
-    Expert,
-}
-
-impl Display for SudokuBoardDifficulty {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            SudokuBoardDifficulty::Easy => write!(f, "Easy"),
-            SudokuBoardDifficulty::Normal => write!(f, "Normal"),
-            SudokuBoardDifficulty::Hard => write!(f, "Hard"),
-            SudokuBoardDifficulty::Expert => write!(f, "Expert"),
-        }
-    }
-}
+pub const DEFAULT_HELP_TEXT: &str = "Use 'Space' to update possible values, 'Shift+Space' to recompute them from scratch, 'Enter' to resolve blocks, 'R' to reset, 'M' to change selection mode, 'C' to clear block, 'Z' to undo, 'Y' to redo, 1 to 9 to set number, 'S', 'H', 'L' or 'P' to engage Naked single, Hidden single, Locked candidates or Naked pair strategy and 'Tab' for the next page of strategies.";
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct BoardId {
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use bevy::{ecs::system::SystemParam, prelude::*};
-use sudoku_solver::SudokuBoard;
+use sudoku_solver::{SudokuBoard, history::BoardHistory};
 
 use crate::shared::{BoardId, SudokuBoardDifficulty};
 
@@ -131,9 +131,26 @@ pub struct Stats {
 pub struct BoardState {
     pub stats: Stats,
     pub playing_state: BoardPlayingState,
+    /// Changes made to the board since the last reset, to be undone.
+    pub history: BoardHistory,
 }
 
 #[derive(Debug, Resource, Default, Clone, Deref, DerefMut)]
 pub struct BoardsStateMap {
     boards: HashMap<BoardId, BoardState>,
 }
+
+impl BoardsStateMap {
+    /// Makes changes to the board, recording them in the history of the board so they can be undone.
+    pub fn record<R>(
+        &mut self,
+        board_id: &BoardId,
+        board: &mut SudokuBoard,
+        change: impl FnOnce(&mut SudokuBoard) -> R,
+    ) -> R {
+        match self.boards.get_mut(board_id) {
+            Some(state) => state.history.record(board, change),
+            None => change(board),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    SudokuBoard,
+    strategies::{Strategy, pipeline::StrategyPipeline},
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SudokuBoardDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Expert,
+}
+
+impl Display for SudokuBoardDifficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SudokuBoardDifficulty::Easy => write!(f, "Easy"),
+            SudokuBoardDifficulty::Normal => write!(f, "Normal"),
+            SudokuBoardDifficulty::Hard => write!(f, "Hard"),
+            SudokuBoardDifficulty::Expert => write!(f, "Expert"),
+        }
+    }
+}
+
+impl SudokuBoardDifficulty {
+    pub const ALL: [SudokuBoardDifficulty; 4] = [
+        SudokuBoardDifficulty::Easy,
+        SudokuBoardDifficulty::Normal,
+        SudokuBoardDifficulty::Hard,
+        SudokuBoardDifficulty::Expert,
+    ];
+}
+
+/// The result of rating a board using [`rate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifficultyReport {
+    pub difficulty: SudokuBoardDifficulty,
+    /// Sum of [`Strategy::score`] for every time a strategy was used.
+    pub score: u32,
+    /// How many times each strategy was used to solve the board.
+    pub strategies: HashMap<Strategy, usize>,
+    /// `false` if human strategies got stuck before solving the board.
+    pub solved: bool,
+}
+
+impl DifficultyReport {
+    /// The hardest strategy needed to solve the board (as far as it got).
+    pub fn hardest_strategy(&self) -> Option<Strategy> {
+        self.strategies.keys().copied().max_by_key(|f| f.score())
+    }
+}
+
+/// Rates the board by solving it using only human strategies from [`crate::strategies`].
+///
+/// Boards that can't be solved this way are considered [`SudokuBoardDifficulty::Expert`].
+pub fn rate(board: &SudokuBoard) -> DifficultyReport {
+    let mut board = board.clone();
+    board.recompute_possibilities();
+
+    let report = StrategyPipeline::default()
+        .assuming_unique(board.is_uniquely_solvable())
+        .run(&mut board);
+    let solved = report.is_solved();
+    let strategies = report.strategy_counts();
+    let score = strategies
+        .iter()
+        .map(|(strategy, count)| strategy.score() * *count as u32)
+        .sum();
+    let difficulty = if solved {
+        strategies
+            .keys()
+            .map(|f| f.difficulty())
+            .max()
+            .unwrap_or(SudokuBoardDifficulty::Easy)
+    } else {
+        SudokuBoardDifficulty::Expert
+    };
+
+    DifficultyReport {
+        difficulty,
+        score,
+        strategies,
+        solved,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_samples() {
+        for sample in [sudoku_samples::easy::FIRST, sudoku_samples::normal::FIRST] {
+            let report = rate(&SudokuBoard::from_u8(sample));
+            let empty_blocks = sample.iter().flatten().filter(|f| f.is_none()).count();
+
+            assert!(report.solved);
+            assert_eq!(report.difficulty, SudokuBoardDifficulty::Easy);
+            assert_eq!(report.hardest_strategy(), Some(Strategy::NakedSingle));
+            assert_eq!(report.strategies[&Strategy::NakedSingle], empty_blocks);
+            assert_eq!(report.score, empty_blocks as u32);
+        }
+    }
+
+    #[test]
+    fn test_rate_stuck_board() {
+        let report = rate(&SudokuBoard::default());
+
+        assert!(!report.solved);
+        assert_eq!(report.difficulty, SudokuBoardDifficulty::Expert);
+        assert!(report.strategies.is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+
+use crate::{
+    SudokuBoard,
+    difficulty::{SudokuBoardDifficulty, rate},
+    solver::Grid,
+};
+
+/// Generates a board with a unique solution for the given difficulty.
+///
+/// The result can be used directly with [`crate::SudokuBoard::from_u8`].
+pub fn generate(rng: &mut impl Rng, difficulty: SudokuBoardDifficulty) -> [[Option<u8>; 9]; 9] {
+    loop {
+        if let Some(numbers) = try_generate(rng, difficulty) {
+            return numbers;
+        }
+    }
+}
+
+/// Same as [`generate`], but the board is always the same for a given `seed`.
+pub fn generate_from_seed(seed: u64, difficulty: SudokuBoardDifficulty) -> [[Option<u8>; 9]; 9] {
+    generate(&mut StdRng::seed_from_u64(seed), difficulty)
+}
+
+/// Fills a random grid and removes numbers while the solution stays unique, then puts back
+/// the last removed ones until the board isn't rated harder than the requested difficulty.
+/// `None` means the board couldn't reach it.
+fn try_generate(
+    rng: &mut impl Rng,
+    difficulty: SudokuBoardDifficulty,
+) -> Option<[[Option<u8>; 9]; 9]> {
+    let mut full = Grid::empty();
+    full.fill_random(rng);
+
+    let mut cells = (0..81).collect::<Vec<_>>();
+    cells.shuffle(rng);
+
+    let mut grid = full.clone();
+    let mut removed = vec![];
+    for cell in cells {
+        let number = grid.remove(cell);
+        if grid.clone().count_solutions(2) == 1 {
+            removed.push(cell);
+        } else {
+            grid.place(cell, number);
+        }
+    }
+
+    // The grid with only the first `count` numbers removed, its solution is still unique.
+    let removing = |count: usize| {
+        let mut grid = full.clone();
+        for cell in &removed[..count] {
+            grid.remove(*cell);
+        }
+        grid
+    };
+    let rate_grid = |grid: &Grid| rate(&SudokuBoard::from_u8(grid.to_u8())).difficulty;
+
+    let mut rated = rate_grid(&grid);
+    if rated > difficulty {
+        // Removing fewer numbers doesn't make the board harder, so search for the most
+        // that can be removed. `low` is never rated harder, `high` always is.
+        let (mut low, mut high) = (0, removed.len());
+        rated = SudokuBoardDifficulty::Easy;
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            let middle_rated = rate_grid(&removing(middle));
+            if middle_rated > difficulty {
+                high = middle;
+            } else {
+                (low, rated) = (middle, middle_rated);
+            }
+        }
+        grid = removing(low);
+    }
+
+    (rated == difficulty).then(|| grid.to_u8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for (seed, difficulty) in SudokuBoardDifficulty::ALL.into_iter().enumerate() {
+            let numbers = generate_from_seed(seed as u64, difficulty);
+            let board = SudokuBoard::from_u8(numbers);
+
+            assert!(board.is_uniquely_solvable());
+            assert_eq!(rate(&board).difficulty, difficulty);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_same_board() {
+        assert_eq!(
+            generate_from_seed(42, SudokuBoardDifficulty::Hard),
+            generate_from_seed(42, SudokuBoardDifficulty::Hard)
+        );
+    }
+}
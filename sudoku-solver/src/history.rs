@@ -0,0 +1,223 @@
+//! Undo and redo for changes made to a [`SudokuBoard`].
+
+use crate::{BlockChange, SudokuBoard, units};
+
+/// Changes made to a board in order, so they can be undone and redone.
+///
+/// Each entry is a group of [`BlockChange`]s that are undone together, like a number placed
+/// along with the possible values it removed from its peers.
+#[derive(Debug, Default, Clone)]
+pub struct BoardHistory {
+    /// The last one is undone first.
+    done: Vec<Vec<BlockChange>>,
+    /// The last one is redone first.
+    undone: Vec<Vec<BlockChange>>,
+    /// Names of checkpoints and how many entries were done when they were made.
+    checkpoints: Vec<(String, usize)>,
+}
+
+impl BoardHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds changes that are already made to the board as one entry.
+    ///
+    /// Anything undone can't be redone after this, checkpoints made after this point are lost.
+    pub fn push(&mut self, changes: Vec<BlockChange>) {
+        if changes.is_empty() {
+            return;
+        }
+
+        self.undone.clear();
+        let done = self.done.len();
+        self.checkpoints.retain(|(_, at)| *at <= done);
+        self.done.push(changes);
+    }
+
+    /// Makes changes to the board using `change`, recording whatever it changed as one entry.
+    pub fn record<R>(
+        &mut self,
+        board: &mut SudokuBoard,
+        change: impl FnOnce(&mut SudokuBoard) -> R,
+    ) -> R {
+        let before = board.clone();
+        let result = change(board);
+        self.push(changes_between(&before, board));
+        result
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.done.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.undone.is_empty()
+    }
+
+    /// Undoes the last entry, returns `false` if there's nothing to undo.
+    pub fn undo(&mut self, board: &mut SudokuBoard) -> bool {
+        let Some(changes) = self.done.pop() else {
+            return false;
+        };
+
+        board.undo_changes(&changes);
+        self.undone.push(changes);
+        true
+    }
+
+    /// Redoes the last undone entry, returns `false` if there's nothing to redo.
+    pub fn redo(&mut self, board: &mut SudokuBoard) -> bool {
+        let Some(changes) = self.undone.pop() else {
+            return false;
+        };
+
+        board.redo_changes(&changes);
+        self.done.push(changes);
+        true
+    }
+
+    /// Names the current point of the history, replacing any checkpoint with the same name.
+    pub fn checkpoint(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        self.checkpoints.retain(|(f, _)| *f != name);
+        self.checkpoints.push((name, self.done.len()));
+    }
+
+    /// Names of the checkpoints that can still be restored, oldest first.
+    pub fn checkpoints(&self) -> impl Iterator<Item = &str> {
+        self.checkpoints.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Undoes or redoes entries until the board is back at the checkpoint.
+    ///
+    /// Returns `false` if there's no such checkpoint.
+    pub fn restore(&mut self, name: &str, board: &mut SudokuBoard) -> bool {
+        let Some(at) = self
+            .checkpoints
+            .iter()
+            .find(|(f, _)| f == name)
+            .map(|(_, at)| *at)
+        else {
+            return false;
+        };
+
+        while self.done.len() > at {
+            self.undo(board);
+        }
+
+        while self.done.len() < at {
+            self.redo(board);
+        }
+
+        true
+    }
+
+    /// Forgets every entry and checkpoint.
+    pub fn clear(&mut self) {
+        self.done.clear();
+        self.undone.clear();
+        self.checkpoints.clear();
+    }
+}
+
+/// Every block whose status is different in the two boards.
+pub fn changes_between(before: &SudokuBoard, after: &SudokuBoard) -> Vec<BlockChange> {
+    units::indexes()
+        .iter()
+        .filter_map(|index| {
+            let (before, after) = (
+                &before.get_block(index).status,
+                &after.get_block(index).status,
+            );
+            (before != after).then(|| BlockChange {
+                index: index.clone(),
+                before: before.clone(),
+                after: after.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlockIndex, SudokuBlockStatus, numbers::SudokuNumber};
+
+    use super::*;
+
+    fn sample() -> SudokuBoard {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        board.update_possibilities();
+        board
+    }
+
+    /// The first possible number of the first block that has any.
+    fn possible(board: &SudokuBoard) -> (BlockIndex, SudokuNumber) {
+        board
+            .get_blocks()
+            .filter_map(|f| Some((f.index().clone(), f.status.as_possibilities()?.numbers)))
+            .next()
+            .map(|(index, numbers)| (index, numbers.iter().next().unwrap()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let start = sample();
+        let mut board = start.clone();
+        let mut history = BoardHistory::new();
+        assert!(!history.undo(&mut board));
+
+        let (index, number) = possible(&board);
+        history.push(board.place(&index, number));
+        let placed = board.clone();
+
+        history.record(&mut board, |f| f.resolve_satisfied_blocks());
+        let resolved = board.clone();
+        assert_ne!(placed, resolved);
+
+        assert!(history.undo(&mut board));
+        assert_eq!(board, placed);
+        assert!(history.undo(&mut board));
+        assert_eq!(board, start);
+        assert!(!history.can_undo());
+
+        assert!(history.redo(&mut board));
+        assert!(history.redo(&mut board));
+        assert_eq!(board, resolved);
+        assert!(!history.can_redo());
+
+        // Nothing to redo after a new change.
+        history.undo(&mut board);
+        history.record(&mut board, |f| {
+            f.get_block_mut(&index).status = SudokuBlockStatus::Unresolved
+        });
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_checkpoints() {
+        let start = sample();
+        let mut board = start.clone();
+        let mut history = BoardHistory::new();
+
+        history.checkpoint("start");
+        let (index, number) = possible(&board);
+        history.push(board.place(&index, number));
+        history.checkpoint("placed");
+        let placed = board.clone();
+        let (index, number) = possible(&board);
+        history.push(board.place(&index, number));
+
+        assert!(history.restore("start", &mut board));
+        assert_eq!(board, start);
+        assert!(history.restore("placed", &mut board));
+        assert_eq!(board, placed);
+        assert!(!history.restore("missing", &mut board));
+
+        // A checkpoint that can't be redone anymore is lost.
+        history.restore("start", &mut board);
+        history.push(board.place(&index, number));
+        assert_eq!(history.checkpoints().collect::<Vec<_>>(), ["start"]);
+    }
+}
@@ -2,13 +2,22 @@ use std::collections::HashMap;
 
 use crate::{
     numbers::{SudokuNumber, SudokuNumbers},
-    strategies::{StrategyMarker, SudokuSolvingStrategy},
+    solver::{Grid, SolveError},
+    strategies::{
+        StrategyEffect, StrategyMarker, StrategyOutcome, SudokuSolvingStrategy,
+        naked_single::NakedSingleStrategy,
+    },
 };
 
 use SudokuNumber::*;
 
+pub mod difficulty;
+pub mod generator;
+pub mod history;
 pub mod numbers;
+pub mod solver;
 pub mod strategies;
+pub mod units;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct BlockIndex {
@@ -36,6 +45,39 @@ impl BlockIndex {
     pub fn square_number(&self) -> SudokuNumber {
         SudokuBoard::square_number(self.row, self.col)
     }
+
+    /// Where the block is in the board, from 0 for r1c1 to 80 for r9c9.
+    pub fn position(&self) -> usize {
+        self.row.to_index() * 9 + self.col.to_index()
+    }
+
+    /// Blocks in the same row, column or square, see [`units::peers`].
+    pub fn peers(&self) -> &'static [BlockIndex; 20] {
+        units::peers(self)
+    }
+
+    /// The number of the row, column or square this block is in.
+    pub fn container_number(&self, container_type: ContainerType) -> SudokuNumber {
+        match container_type {
+            ContainerType::Row => self.row,
+            ContainerType::Column => self.col,
+            ContainerType::Square => self.square_number(),
+        }
+    }
+
+    /// Returns `true` if the other block is a different block in the same row, column or square.
+    pub fn is_peer_of(&self, other: &BlockIndex) -> bool {
+        self != other
+            && (self.row == other.row
+                || self.col == other.col
+                || self.square_number() == other.square_number())
+    }
+}
+
+impl std::fmt::Display for BlockIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "r{}c{}", self.row.to_u8(), self.col.to_u8())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -46,6 +88,8 @@ pub enum Conflicting {
         number: SudokuNumber,
     },
     Source,
+    /// Disagrees with the solution remembered by [`SudokuBoard::remember_solution`].
+    Mistake,
 }
 
 impl Conflicting {
@@ -100,6 +144,14 @@ impl Conflicting {
     pub fn is_source(&self) -> bool {
         matches!(self, Self::Source)
     }
+
+    /// Returns `true` if the conflicting is [`Mistake`].
+    ///
+    /// [`Mistake`]: Conflicting::Mistake
+    #[must_use]
+    pub fn is_mistake(&self) -> bool {
+        matches!(self, Self::Mistake)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -155,6 +207,9 @@ impl SudokuBlock {
 pub struct Possibilities {
     pub numbers: SudokuNumbers,
     conflicting_numbers: SudokuNumbers,
+    /// Removed by hand or by an applied strategy, [`SudokuBoard::update_possibilities`]
+    /// doesn't add them back.
+    removed: SudokuNumbers,
     strategy_markers: HashMap<SudokuNumber, StrategyMarker>,
 }
 
@@ -163,10 +218,29 @@ impl Possibilities {
         Self {
             numbers,
             conflicting_numbers: Default::default(),
+            removed: Default::default(),
             strategy_markers: Default::default(),
         }
     }
 
+    /// Removes the number and remembers it was removed, so it's not added back
+    /// by [`SudokuBoard::update_possibilities`].
+    pub fn remove_number(&mut self, number: SudokuNumber) {
+        self.numbers.del_number(number);
+        self.removed.set_number(number);
+    }
+
+    /// Adds the number, even if it was removed before.
+    pub fn add_number(&mut self, number: SudokuNumber) {
+        self.numbers.set_number(number);
+        self.removed.del_number(number);
+    }
+
+    /// Whether the number was removed by hand or by an applied strategy.
+    pub fn is_removed(&self, number: SudokuNumber) -> bool {
+        self.removed.has_number(number)
+    }
+
     pub fn is_conflicting(&self, number: SudokuNumber) -> bool {
         self.numbers.has_number(number) && self.conflicting_numbers.has_number(number)
     }
@@ -263,6 +337,14 @@ impl SudokuBlockStatus {
     }
 }
 
+/// A change of a block's status made by [`SudokuBoard::place`], enough to undo or redo it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockChange {
+    pub index: BlockIndex,
+    pub before: SudokuBlockStatus,
+    pub after: SudokuBlockStatus,
+}
+
 #[derive(Debug, Clone)]
 /// Refers to a row, column or an square in a sudoku board (Typically a sequence of blocks).
 pub struct SudokuContainer<T, Item>
@@ -332,9 +414,29 @@ pub enum ContainerType {
     Square,
 }
 
+impl ContainerType {
+    pub const ALL: [ContainerType; 3] = [
+        ContainerType::Row,
+        ContainerType::Column,
+        ContainerType::Square,
+    ];
+}
+
+impl std::fmt::Display for ContainerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ContainerType::Row => write!(f, "row"),
+            ContainerType::Column => write!(f, "column"),
+            ContainerType::Square => write!(f, "square"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SudokuBoard {
     blocks: [[SudokuBlock; 9]; 9],
+    /// Numbers of the unique solution by position, see [`SudokuBoard::remember_solution`].
+    solution: Option<[SudokuNumber; 81]>,
 }
 
 impl Default for SudokuBoard {
@@ -353,13 +455,17 @@ impl Default for SudokuBoard {
             .try_into()
             .unwrap();
 
-        Self { blocks }
+        Self {
+            blocks,
+            solution: None,
+        }
     }
 }
 
 impl SudokuBoard {
     /// Fills the board with given numbers or empty blocks.
     fn fill_board(&mut self, numbers: [[Option<SudokuNumber>; 9]; 9]) {
+        self.solution = None;
         for (row_index, row) in numbers.iter().enumerate() {
             for (col_index, &number_option) in row.iter().enumerate() {
                 let index = BlockIndex::from_index(row_index, col_index).unwrap();
@@ -436,11 +542,23 @@ impl SudokuBoard {
         container_type: ContainerType,
         number: SudokuNumber,
     ) -> Vec<&SudokuBlock> {
-        match container_type {
-            ContainerType::Row => self.get_row(number).collect(),
-            ContainerType::Column => self.get_col(number).collect(),
-            ContainerType::Square => self.get_square(number).collect(),
-        }
+        self.iter_container(container_type, number).collect()
+    }
+
+    /// Same as [`SudokuBoard::get_container`], without collecting the blocks.
+    pub fn iter_container(
+        &self,
+        container_type: ContainerType,
+        number: SudokuNumber,
+    ) -> impl Iterator<Item = &SudokuBlock> {
+        units::container(container_type, number)
+            .iter()
+            .map(|f| self.get_block(f))
+    }
+
+    /// Blocks in the same row, column or square as the block at `index`.
+    pub fn iter_peers(&self, index: &BlockIndex) -> impl Iterator<Item = &SudokuBlock> {
+        index.peers().iter().map(|f| self.get_block(f))
     }
 
     pub fn get_container_mut(
@@ -542,66 +660,234 @@ impl SudokuBoard {
     }
 
     pub fn get_block_possible_numbers(&self, index: &BlockIndex) -> SudokuNumbers {
-        let mut possible_numbers = SudokuNumbers::new_all();
-
-        possible_numbers.del_numbers(
-            self.get_row(index.row)
-                .get_numbers()
-                .into_iter()
-                .chain(self.get_col(index.col).get_numbers().into_iter())
-                .chain(
-                    self.get_square(index.square_number())
-                        .get_numbers()
-                        .into_iter(),
-                ),
-        );
-
-        possible_numbers
+        SudokuNumbers::new_all().difference(&SudokuBoard::get_numbers(self.iter_peers(index)))
     }
 
-    /// Updates possible values for each [`SudokuBlockStatus::Unresolved`] or [`SudokuBlockStatus::Possibilities`]
-    /// block based on [`SudokuBlockStatus::Fixed`] blocks values.
+    /// Sets possible values of every [`SudokuBlockStatus::Unresolved`] or
+    /// [`SudokuBlockStatus::Possibilities`] block to the ones still possible based on
+    /// [`SudokuBlockStatus::Fixed`] and [`SudokuBlockStatus::Resolved`] blocks values.
+    ///
+    /// Values removed by hand or by a strategy (see [`Possibilities::remove_number`]) aren't
+    /// added back and strategy markers are kept.
+    /// Use [`SudokuBoard::recompute_possibilities`] to start over.
     pub fn update_possibilities(&mut self) {
-        use SudokuNumber::*;
+        for index in units::indexes() {
+            let possibles = match self.get_block(index).status {
+                SudokuBlockStatus::Unresolved | SudokuBlockStatus::Possibilities(_) => {
+                    self.get_block_possible_numbers(index)
+                }
+                _ => continue,
+            };
 
-        // self.get_blocks_mut()
-        //     .filter(|block| block.is_possibilities() || block.is_unresolved())
-        //     .for_each(|block| {
-        //         let possibles = self.get_block_possible_numbers(block.index());
-        //         block.status = SudokuBlockStatus::Possibilities(Possibilities::new(possibles));
-        //     });
-
-        for row in [One, Two, Three, Four, Five, Six, Seven, Eight, Nine] {
-            for col in [One, Two, Three, Four, Five, Six, Seven, Eight, Nine] {
-                let index = BlockIndex::new(row, col);
-                if let SudokuBlockStatus::Unresolved | SudokuBlockStatus::Possibilities(_) =
-                    self.get_block(&index).status
-                {
-                    let possibles = self.get_block_possible_numbers(&index);
-                    let block = self.get_block_mut(&index);
-                    block.status = SudokuBlockStatus::Possibilities(Possibilities::new(possibles));
+            let block = self.get_block_mut(index);
+            match &mut block.status {
+                SudokuBlockStatus::Possibilities(possibilities) => {
+                    possibilities.numbers = possibles.difference(&possibilities.removed);
                 }
+                status => *status = SudokuBlockStatus::Possibilities(Possibilities::new(possibles)),
             }
         }
     }
 
-    pub fn engage_strategy<S>(&mut self, strategy: S, show_only_effect: bool)
+    /// Replaces possible values of every [`SudokuBlockStatus::Unresolved`] or
+    /// [`SudokuBlockStatus::Possibilities`] block with the ones still possible based on
+    /// [`SudokuBlockStatus::Fixed`] and [`SudokuBlockStatus::Resolved`] blocks values.
+    ///
+    /// Unlike [`SudokuBoard::update_possibilities`], values removed by hand are added back
+    /// and strategy markers are cleared.
+    pub fn recompute_possibilities(&mut self) {
+        for index in units::indexes() {
+            if let SudokuBlockStatus::Unresolved | SudokuBlockStatus::Possibilities(_) =
+                self.get_block(index).status
+            {
+                let possibles = self.get_block_possible_numbers(index);
+                let block = self.get_block_mut(index);
+                block.status = SudokuBlockStatus::Possibilities(Possibilities::new(possibles));
+            }
+        }
+    }
+
+    pub fn engage_strategy<S>(&mut self, strategy: &S, show_only_effect: bool)
     where
-        S: SudokuSolvingStrategy,
+        S: SudokuSolvingStrategy + ?Sized,
     {
-        // self.update_possibilities();
-        strategy.update_possible_numbers(self, show_only_effect);
+        let outcome = strategy.outcome(self);
+        if show_only_effect {
+            self.preview_strategy(&outcome);
+        } else {
+            self.apply_strategy(&outcome);
+        }
     }
 
-    pub fn resolve_satisfied_blocks(&mut self) {
-        for block in self.get_blocks_mut().filter(|f| f.is_possibilities()) {
-            let possibles = block.status.as_possibilities().unwrap();
-            if possibles.numbers.count_numbers() == 1 {
-                let single_naked = possibles.numbers.iter().next().unwrap();
-                block.status = SudokuBlockStatus::Resolved(single_naked);
+    /// Marks sources and effects of the outcome on possible numbers, without changing anything else.
+    pub fn preview_strategy(&mut self, outcome: &StrategyOutcome) {
+        for step in &outcome.steps {
+            for (index, number) in &step.sources {
+                if let Some(possibilities) = self.get_block_mut(index).status.as_possibilities_mut()
+                {
+                    possibilities.update_strategy_marker(
+                        *number,
+                        StrategyMarker::new(outcome.strategy, StrategyEffect::Source)
+                            .with_color(step.color_of(index, *number)),
+                    );
+                }
             }
         }
 
+        for elimination in outcome.eliminations() {
+            let Some(source) = elimination.source else {
+                continue;
+            };
+
+            if let Some(possibilities) = self
+                .get_block_mut(&elimination.index)
+                .status
+                .as_possibilities_mut()
+            {
+                possibilities.update_strategy_marker(
+                    elimination.number,
+                    StrategyMarker::new(
+                        outcome.strategy,
+                        StrategyEffect::Effected {
+                            index: source,
+                            number: Some(elimination.number),
+                        },
+                    ),
+                );
+            }
+        }
+    }
+
+    /// Applies placements and eliminations of the outcome to the board.
+    ///
+    /// Placed numbers are removed from their peers as well, see [`SudokuBoard::place`].
+    pub fn apply_strategy(&mut self, outcome: &StrategyOutcome) {
+        self.clear_strategy_markers();
+
+        for elimination in outcome.eliminations() {
+            if let Some(possibilities) = self
+                .get_block_mut(&elimination.index)
+                .status
+                .as_possibilities_mut()
+            {
+                possibilities.remove_number(elimination.number);
+            }
+        }
+
+        for (index, number) in outcome.placements() {
+            let block = self.get_block(&index);
+            if block.is_possibilities() || block.is_unresolved() {
+                self.place(&index, number);
+            }
+        }
+    }
+
+    /// Resolves the block with `number` and removes it from possible values of every peer.
+    ///
+    /// Returns every change made, in order. [`SudokuBlockStatus::Fixed`] blocks are left
+    /// untouched, so nothing is returned for them.
+    pub fn place(&mut self, index: &BlockIndex, number: SudokuNumber) -> Vec<BlockChange> {
+        let block = self.get_block_mut(index);
+        if block.is_fixed() {
+            return vec![];
+        }
+
+        let mut changes = vec![BlockChange {
+            index: index.clone(),
+            before: std::mem::replace(&mut block.status, SudokuBlockStatus::Resolved(number)),
+            after: SudokuBlockStatus::Resolved(number),
+        }];
+
+        for peer in index.peers() {
+            let status = &mut self.get_block_mut(peer).status;
+            let before = match status.as_possibilities_mut() {
+                Some(possibilities) if possibilities.numbers.has_number(number) => {
+                    let before = possibilities.clone();
+                    possibilities.numbers.del_number(number);
+                    SudokuBlockStatus::Possibilities(before)
+                }
+                _ => continue,
+            };
+
+            changes.push(BlockChange {
+                index: peer.clone(),
+                before,
+                after: status.clone(),
+            });
+        }
+
+        changes
+    }
+
+    /// Clears the block back to [`SudokuBlockStatus::Unresolved`], undoing [`SudokuBoard::place`].
+    ///
+    /// A resolved number is given back to the possible values of every peer, unless another
+    /// resolved or fixed block still sees it there or it was removed from the peer before.
+    /// Returns every change made, in order.
+    pub fn unplace(&mut self, index: &BlockIndex) -> Vec<BlockChange> {
+        let block = self.get_block_mut(index);
+        if block.is_fixed() || block.is_unresolved() {
+            return vec![];
+        }
+
+        let before = std::mem::replace(&mut block.status, SudokuBlockStatus::Unresolved);
+        let number = before.as_resolved().copied();
+        let mut changes = vec![BlockChange {
+            index: index.clone(),
+            before,
+            after: SudokuBlockStatus::Unresolved,
+        }];
+        let Some(number) = number else {
+            return changes;
+        };
+
+        for peer in index.peers() {
+            let blocked = self
+                .iter_peers(peer)
+                .any(|f| f.status.as_resolved().or(f.status.as_fixed()) == Some(&number));
+            let status = &mut self.get_block_mut(peer).status;
+            let before = match status.as_possibilities_mut() {
+                Some(possibilities)
+                    if !blocked
+                        && !possibilities.numbers.has_number(number)
+                        && !possibilities.is_removed(number) =>
+                {
+                    let before = possibilities.clone();
+                    possibilities.numbers.set_number(number);
+                    SudokuBlockStatus::Possibilities(before)
+                }
+                _ => continue,
+            };
+
+            changes.push(BlockChange {
+                index: peer.clone(),
+                before,
+                after: status.clone(),
+            });
+        }
+
+        changes
+    }
+
+    /// Puts back every block as it was before the changes.
+    pub fn undo_changes(&mut self, changes: &[BlockChange]) {
+        for change in changes.iter().rev() {
+            self.get_block_mut(&change.index).status = change.before.clone();
+        }
+    }
+
+    /// Makes the changes again, after they're undone by [`SudokuBoard::undo_changes`].
+    pub fn redo_changes(&mut self, changes: &[BlockChange]) {
+        for change in changes {
+            self.get_block_mut(&change.index).status = change.after.clone();
+        }
+    }
+
+    /// Resolves every naked single using [`NakedSingleStrategy`], removing its number from the peers.
+    pub fn resolve_satisfied_blocks(&mut self) {
+        let outcome = NakedSingleStrategy.outcome(self);
+        self.apply_strategy(&outcome);
+
         if self.mark_all_conflicts() {
             self.update_possibilities();
         }
@@ -618,18 +904,9 @@ impl SudokuBoard {
     }
 
     fn find_mistakes(&self, index: &BlockIndex, number: SudokuNumber) -> Option<Vec<BlockIndex>> {
-        let row_m =
-            SudokuBoard::find_similar_in_container(number, self.get_row(index.row), Some(index));
-        let col_m =
-            SudokuBoard::find_similar_in_container(number, self.get_col(index.col), Some(index));
-        let square_m = SudokuBoard::find_similar_in_container(
-            number,
-            self.get_square(index.square_number()),
-            Some(index),
-        );
-
-        let mut mistakes = row_m.chain(col_m).chain(square_m).collect::<Vec<_>>();
-        mistakes.dedup();
+        let mistakes =
+            SudokuBoard::find_similar_in_container(number, self.iter_peers(index), Some(index))
+                .collect::<Vec<_>>();
 
         if mistakes.is_empty() {
             None
@@ -651,14 +928,22 @@ impl SudokuBoard {
     ) -> bool {
         let block_status = &self.get_block(index).status;
 
-        match block_status {
+        let verified = match block_status {
             SudokuBlockStatus::Unresolved => self.mark_conflicts_unresolved(index),
             SudokuBlockStatus::Resolved(_) => self.mark_conflicts_resolved(index),
             SudokuBlockStatus::Possibilities(_) => {
                 self.mark_conflicts_possibilities(index, possibility_number_info)
             }
             SudokuBlockStatus::Fixed(_) => true,
+        };
+
+        // Placing a number may have removed the right one from its peers.
+        self.mark_mistake(index);
+        for peer in index.peers() {
+            self.mark_mistake(peer);
         }
+
+        verified
     }
 
     fn mark_conflicts_possibilities(
@@ -679,14 +964,17 @@ impl SudokuBoard {
                     .count_numbers()
                     == 1
                 {
-                    // Clean up previous conflicts
-                    self.get_blocks_mut()
-                        .filter(|f| {
-                            f.conflicting
-                                .as_ref()
-                                .is_some_and(|conf| conf.is_affected_by_and(|f| f == index))
-                        })
-                        .for_each(|f| f.conflicting = None);
+                    // Clean up previous conflicts, they're only ever marked on peers.
+                    for peer in index.peers() {
+                        let block = self.get_block_mut(peer);
+                        if block
+                            .conflicting
+                            .as_ref()
+                            .is_some_and(|conf| conf.is_affected_by_and(|f| f == index))
+                        {
+                            block.conflicting = None;
+                        }
+                    }
                 }
 
                 if let Some(mistakes) = self.find_mistakes(index, pos) {
@@ -712,15 +1000,16 @@ impl SudokuBoard {
                 let poss = block.status.as_possibilities_mut().unwrap();
                 poss.conflicting_numbers.del_number(pos);
 
-                self.get_blocks_mut()
-                    .filter(|f| {
-                        f.conflicting.as_ref().is_some_and(|conf| {
-                            conf.is_affected_by_possibilities_and(|block_index, number| {
-                                block_index == index && number == &pos
-                            })
+                for peer in index.peers() {
+                    let block = self.get_block_mut(peer);
+                    if block.conflicting.as_ref().is_some_and(|conf| {
+                        conf.is_affected_by_possibilities_and(|block_index, number| {
+                            block_index == index && number == &pos
                         })
-                    })
-                    .for_each(|f| f.conflicting = None);
+                    }) {
+                        block.conflicting = None;
+                    }
+                }
                 true
             }
         } else {
@@ -755,56 +1044,158 @@ impl SudokuBoard {
         true
     }
 
+    /// Conflicts caused by a block are only ever marked on its peers.
     fn clear_all_previous_conflicts(&mut self, index: &BlockIndex) {
-        self.get_blocks_mut()
-            .filter(|f| {
-                f.conflicting.as_ref().is_some_and(|conf| {
-                    conf.is_affected_by_and(|f| f == index)
-                        || conf
-                            .is_affected_by_possibilities_and(|block_index, _| block_index == index)
-                })
-            })
-            .for_each(|f| f.conflicting = None);
+        for peer in index.peers() {
+            let block = self.get_block_mut(peer);
+            if block.conflicting.as_ref().is_some_and(|conf| {
+                conf.is_affected_by_and(|f| f == index)
+                    || conf.is_affected_by_possibilities_and(|block_index, _| block_index == index)
+            }) {
+                block.conflicting = None;
+            }
+        }
     }
 
     pub fn mark_all_conflicts(&mut self) -> bool {
-        use SudokuNumber::*;
-
         let mut verified = true;
-        for row in [One, Two, Three, Four, Five, Six, Seven, Eight, Nine] {
-            for col in [One, Two, Three, Four, Five, Six, Seven, Eight, Nine] {
-                let index = BlockIndex::new(row, col);
-                let block = self.get_block(&index);
-                if !match &block.status {
-                    SudokuBlockStatus::Resolved(_) => self.mark_conflicts_resolved(&index),
-                    SudokuBlockStatus::Unresolved => self.mark_conflicts_unresolved(&index),
-                    _ => true,
-                } {
-                    verified = false;
-                }
+        for index in units::indexes() {
+            if !match &self.get_block(index).status {
+                SudokuBlockStatus::Resolved(_) => self.mark_conflicts_resolved(index),
+                SudokuBlockStatus::Unresolved => self.mark_conflicts_unresolved(index),
+                _ => true,
+            } {
+                verified = false;
             }
         }
 
+        for index in units::indexes() {
+            self.mark_mistake(index);
+        }
+
         verified
     }
 
+    /// Marks the block as [`Conflicting::Mistake`] or clears it, unless it conflicts
+    /// for another reason.
+    fn mark_mistake(&mut self, index: &BlockIndex) {
+        let is_mistake = self.is_mistake(index);
+        let block = self.get_block_mut(index);
+        match block.conflicting {
+            None if is_mistake => block.conflicting = Some(Conflicting::Mistake),
+            Some(Conflicting::Mistake) if !is_mistake => block.conflicting = None,
+            _ => {}
+        }
+    }
+
     pub fn verify_board(&self) -> bool {
-        use SudokuNumber::*;
+        units::indexes().iter().all(|index| {
+            self.get_block(index)
+                .status
+                .as_resolved()
+                .is_none_or(|number| self.find_mistakes(index, *number).is_none())
+        })
+    }
 
-        for row in [One, Two, Three, Four, Five, Six, Seven, Eight, Nine] {
-            for col in [One, Two, Three, Four, Five, Six, Seven, Eight, Nine] {
-                let index = BlockIndex::new(row, col);
-                if let SudokuBlockStatus::Resolved(number) = &self.get_block(&index).status
-                    && self.find_mistakes(&index, *number).is_some()
-                {
-                    return false;
-                }
-            }
+    /// Solves the board using a backtracking search.
+    ///
+    /// [`SudokuBlockStatus::Fixed`] and [`SudokuBlockStatus::Resolved`] blocks are considered as givens
+    /// and any other block is filled with a [`SudokuBlockStatus::Resolved`] number.
+    /// The board is left untouched if it can't be solved.
+    pub fn solve(&mut self) -> Result<(), SolveError> {
+        let grid = Grid::from_board(self)?.solve()?;
+
+        for block in self
+            .get_blocks_mut()
+            .filter(|f| !f.is_fixed() && !f.is_resolved())
+        {
+            block.status = SudokuBlockStatus::Resolved(grid.number_at(&block.index).unwrap());
+            block.conflicting = None;
+        }
+
+        Ok(())
+    }
+
+    /// Same as [`SudokuBoard::solve`] but returns the solved board instead.
+    pub fn solution(&self) -> Result<SudokuBoard, SolveError> {
+        let mut board = self.clone();
+        board.solve()?;
+        Ok(board)
+    }
+
+    /// Counts the solutions of this board, but stops counting as soon as `limit` is reached.
+    ///
+    /// A board with conflicting givens has no solutions.
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        if limit == 0 {
+            return 0;
+        }
+
+        match Grid::from_board(self) {
+            Ok(mut grid) => grid.count_solutions(limit),
+            Err(_) => 0,
         }
+    }
+
+    /// Returns `true` if the board has exactly one solution.
+    pub fn is_uniquely_solvable(&self) -> bool {
+        self.count_solutions(2) == 1
+    }
 
+    /// Solves the fixed blocks and keeps the solution, so [`SudokuBoard::mark_conflicts`]
+    /// also marks blocks that disagree with it as [`Conflicting::Mistake`].
+    ///
+    /// Returns `false` and forgets any previous solution if the solution isn't unique.
+    pub fn remember_solution(&mut self) -> bool {
+        self.solution = None;
+
+        let mut givens = self.clone();
+        givens.reset();
+
+        // Counting stops at the second solution, the first one is kept along the way.
+        let Ok(mut grid) = Grid::from_board(&givens) else {
+            return false;
+        };
+        let (1, Some(grid)) = grid.count_solutions_with_first(2) else {
+            return false;
+        };
+        self.solution = Some(std::array::from_fn(|position| {
+            grid.number_at(&units::indexes()[position]).unwrap()
+        }));
         true
     }
 
+    pub fn forget_solution(&mut self) {
+        self.solution = None;
+    }
+
+    pub fn has_solution(&self) -> bool {
+        self.solution.is_some()
+    }
+
+    /// Number of the block in the remembered solution.
+    pub fn solution_number(&self, index: &BlockIndex) -> Option<SudokuNumber> {
+        self.solution.as_ref().map(|f| f[index.position()])
+    }
+
+    /// Returns `true` if the block is resolved to another number than the remembered solution,
+    /// or the right number was removed from its possible numbers.
+    ///
+    /// Always `false` without a remembered solution.
+    pub fn is_mistake(&self, index: &BlockIndex) -> bool {
+        let Some(expected) = self.solution_number(index) else {
+            return false;
+        };
+
+        match &self.get_block(index).status {
+            SudokuBlockStatus::Resolved(number) => *number != expected,
+            SudokuBlockStatus::Possibilities(possibilities) => {
+                !possibilities.numbers.has_number(expected)
+            }
+            SudokuBlockStatus::Unresolved | SudokuBlockStatus::Fixed(_) => false,
+        }
+    }
+
     pub fn clear_strategy_markers(&mut self) {
         self.get_blocks_mut()
             .filter_map(|f| f.status.as_possibilities_mut())
@@ -881,6 +1272,8 @@ impl SudokuBoard {
 
 #[cfg(test)]
 mod tests {
+    use crate::strategies::peers_having;
+
     use super::*;
 
     #[test]
@@ -904,4 +1297,250 @@ mod tests {
         board.get_block_mut(&BlockIndex::new(One, One)).status = SudokuBlockStatus::Resolved(Seven);
         //TODO -
     }
+
+    #[test]
+    fn test_place() {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        board.update_possibilities();
+        let before = board.clone();
+
+        let (index, number) = board
+            .get_blocks()
+            .find_map(|f| Some((f.index().clone(), f.status.as_possibilities()?.numbers)))
+            .map(|(index, numbers)| (index, numbers.iter().next().unwrap()))
+            .unwrap();
+        let changes = board.place(&index, number);
+
+        assert_eq!(changes[0].index, index);
+        assert_eq!(
+            board.get_block(&index).status,
+            SudokuBlockStatus::Resolved(number)
+        );
+        assert_eq!(
+            changes[1..].iter().map(|f| &f.index).collect::<Vec<_>>(),
+            index
+                .peers()
+                .iter()
+                .filter(|f| before
+                    .get_block(f)
+                    .status
+                    .as_possibilities()
+                    .is_some_and(|p| p.numbers.has_number(number)))
+                .collect::<Vec<_>>()
+        );
+        assert!(board.iter_peers(&index).all(|f| {
+            f.status
+                .as_possibilities()
+                .is_none_or(|p| !p.numbers.has_number(number))
+        }));
+
+        let after = board.clone();
+        board.undo_changes(&changes);
+        assert_eq!(board, before);
+        board.redo_changes(&changes);
+        assert_eq!(board, after);
+
+        let fixed = board
+            .get_blocks()
+            .find(|f| f.is_fixed())
+            .unwrap()
+            .index()
+            .clone();
+        assert!(board.place(&fixed, number).is_empty());
+    }
+
+    #[test]
+    fn test_mistakes() {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        board.update_possibilities();
+        let unsolved = board.clone();
+        assert!(board.remember_solution());
+        let solved = unsolved.solution().unwrap();
+        assert!(units::indexes().iter().all(|index| {
+            let block = &solved.get_block(index).status;
+            board.solution_number(index).as_ref() == block.as_resolved().or(block.as_fixed())
+        }));
+
+        // Pick a block whose possible numbers contain a wrong one.
+        let (index, expected, wrong) = board
+            .get_blocks()
+            .filter_map(|f| {
+                let expected = board.solution_number(f.index())?;
+                let wrong = f
+                    .status
+                    .as_possibilities()?
+                    .numbers
+                    .iter()
+                    .find(|n| *n != expected)?;
+                Some((f.index().clone(), expected, wrong))
+            })
+            .next()
+            .unwrap();
+
+        // Wrong, even though it doesn't repeat a peer.
+        board.get_block_mut(&index).status = SudokuBlockStatus::Resolved(wrong);
+        board.mark_conflicts(&index, None);
+        assert!(board.is_mistake(&index));
+        assert_eq!(
+            board.get_block(&index).conflicting,
+            Some(Conflicting::Mistake)
+        );
+
+        board.get_block_mut(&index).status = SudokuBlockStatus::Resolved(expected);
+        board.mark_conflicts(&index, None);
+        assert_eq!(board.get_block(&index).conflicting, None);
+
+        // The right number removed from the possible numbers.
+        board.get_block_mut(&index).status = unsolved.get_block(&index).status.clone();
+        board
+            .get_block_mut(&index)
+            .status
+            .as_possibilities_mut()
+            .unwrap()
+            .numbers
+            .del_number(expected);
+        board.mark_conflicts(&index, Some((expected, true)));
+        assert!(
+            board
+                .get_block(&index)
+                .conflicting
+                .as_ref()
+                .unwrap()
+                .is_mistake()
+        );
+
+        board.forget_solution();
+        board.mark_all_conflicts();
+        assert!(board.get_blocks().all(|f| f.conflicting.is_none()));
+
+        // Without a unique solution nothing is remembered.
+        assert!(!SudokuBoard::default().remember_solution());
+    }
+
+    #[test]
+    fn test_apply_strategy_places() {
+        use crate::strategies::{SolveStep, Strategy, StrategyOutcome};
+
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        board.update_possibilities();
+        let (index, number) = board
+            .get_blocks()
+            .find_map(|f| Some((f.index().clone(), f.status.as_possibilities()?.numbers)))
+            .map(|(index, numbers)| (index, numbers.iter().next().unwrap()))
+            .unwrap();
+
+        // Placed without eliminations of its own.
+        let mut placed = board.clone();
+        placed.place(&index, number);
+        board.apply_strategy(&StrategyOutcome::new(
+            Strategy::NakedSingle,
+            vec![SolveStep::new(Strategy::NakedSingle).with_placement(index, number)],
+        ));
+        assert_eq!(board, placed);
+    }
+
+    #[test]
+    fn test_unplace() {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        board.update_possibilities();
+        assert!(board.remember_solution());
+        let before = board.clone();
+
+        // A wrong number that's also possible in some peer.
+        let (index, wrong) = board
+            .get_blocks()
+            .find_map(|f| {
+                let expected = board.solution_number(f.index())?;
+                let wrong = f.status.as_possibilities()?.numbers.iter().find(|n| {
+                    *n != expected && peers_having(&board, f.index(), *n).next().is_some()
+                })?;
+                Some((f.index().clone(), wrong))
+            })
+            .unwrap();
+
+        board.place(&index, wrong);
+        board.mark_conflicts(&index, None);
+        let changes = board.unplace(&index);
+        board.mark_conflicts(&index, None);
+
+        assert_eq!(changes[0].after, SudokuBlockStatus::Unresolved);
+        assert_eq!(
+            board.get_block(&index).status,
+            SudokuBlockStatus::Unresolved
+        );
+        for peer in index.peers() {
+            assert_eq!(board.get_block(peer).status, before.get_block(peer).status);
+            assert!(!board.is_mistake(peer));
+        }
+        assert!(board.get_blocks().all(|f| f.conflicting.is_none()));
+
+        let fixed = board
+            .get_blocks()
+            .find(|f| f.is_fixed())
+            .unwrap()
+            .index()
+            .clone();
+        assert!(board.unplace(&fixed).is_empty());
+    }
+
+    #[test]
+    fn test_update_possibilities_keeps_removed() {
+        use crate::strategies::Strategy;
+
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        board.update_possibilities();
+
+        let (index, possibles) = board
+            .get_blocks()
+            .filter_map(|f| Some((f.index().clone(), f.status.as_possibilities()?.numbers)))
+            .find(|(_, f)| f.count_numbers() >= 3)
+            .unwrap();
+        let [removed, placed, marked] = possibles.iter().take(3).collect::<Vec<_>>()[..] else {
+            unreachable!();
+        };
+        let peer = board
+            .iter_peers(&index)
+            .find(|f| !f.is_fixed())
+            .unwrap()
+            .index()
+            .clone();
+
+        // Removed by hand and marked by a strategy.
+        let possibilities = board
+            .get_block_mut(&index)
+            .status
+            .as_possibilities_mut()
+            .unwrap();
+        possibilities.remove_number(removed);
+        possibilities.update_strategy_marker(
+            marked,
+            StrategyMarker::new(Strategy::NakedPair, StrategyEffect::Source),
+        );
+
+        // Placed in a peer.
+        board.get_block_mut(&peer).status = SudokuBlockStatus::Resolved(placed);
+        board.update_possibilities();
+
+        let possibilities = board.get_block(&index).status.as_possibilities().unwrap();
+        let mut expected = possibles;
+        expected.del_numbers([removed, placed].into_iter());
+        assert_eq!(possibilities.numbers, expected);
+        assert!(possibilities.has_strategy_effect(&marked).is_some());
+
+        // Cleared from the peer, only the number removed by hand stays removed.
+        board.get_block_mut(&peer).status = SudokuBlockStatus::Unresolved;
+        board.update_possibilities();
+        let possibilities = board.get_block(&index).status.as_possibilities().unwrap();
+        let mut expected = possibles;
+        expected.del_number(removed);
+        assert_eq!(possibilities.numbers, expected);
+        assert!(possibilities.has_strategy_effect(&marked).is_some());
+
+        board.get_block_mut(&peer).status = SudokuBlockStatus::Resolved(placed);
+        board.recompute_possibilities();
+        let possibilities = board.get_block(&index).status.as_possibilities().unwrap();
+        assert!(possibilities.numbers.has_number(removed));
+        assert!(!possibilities.numbers.has_number(placed));
+        assert!(possibilities.has_strategy_effect(&marked).is_none());
+    }
 }
@@ -39,6 +39,11 @@ impl SudokuNumber {
             .into_iter()
             .flat_map(|row| SudokuNumber::ALL.into_iter().map(move |col| (row, col)))
     }
+
+    /// The bit of the number in a [`SudokuNumbers`] mask.
+    const fn bit(self) -> u16 {
+        1 << self as u16
+    }
 }
 
 impl TryFrom<usize> for SudokuNumber {
@@ -76,15 +81,22 @@ impl From<SudokuNumber> for usize {
     }
 }
 
-#[derive(Clone, Default, PartialEq, Eq, Hash)]
+/// Bits of every number, from the lowest bit for [`SudokuNumber::One`].
+const ALL_BITS: u16 = 0b1_1111_1111;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
 pub struct SudokuNumbers {
-    // false means the number is not contained
-    numbers: [bool; 9],
+    // A set bit means the number is contained
+    bits: u16,
 }
 
 impl From<[bool; 9]> for SudokuNumbers {
     fn from(numbers: [bool; 9]) -> Self {
-        Self { numbers }
+        Self::new(
+            SudokuNumber::ALL
+                .into_iter()
+                .filter(|f| numbers[f.to_index()]),
+        )
     }
 }
 
@@ -94,39 +106,33 @@ impl std::fmt::Debug for SudokuNumbers {
     }
 }
 
+impl FromIterator<SudokuNumber> for SudokuNumbers {
+    fn from_iter<T: IntoIterator<Item = SudokuNumber>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
 impl SudokuNumbers {
     pub fn new(numbers: impl IntoIterator<Item = SudokuNumber>) -> Self {
-        let mut real_numbers: [bool; 9] = Default::default();
-        for index in numbers.into_iter().map(|num| num.to_index()) {
-            real_numbers[index] = true;
-        }
         Self {
-            numbers: real_numbers,
+            bits: numbers.into_iter().fold(0, |acc, f| acc | f.bit()),
         }
     }
 
     pub fn new_all() -> Self {
-        Self { numbers: [true; 9] }
+        Self { bits: ALL_BITS }
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = SudokuNumber> {
-        self.numbers
-            .iter()
-            .enumerate()
-            .filter(|(_, available)| **available)
-            .map(|(index, _)| (index + 1).try_into().unwrap())
+    pub fn iter(&self) -> impl Iterator<Item = SudokuNumber> + use<> {
+        Bits(self.bits)
     }
 
     pub fn into_iter(self) -> impl Iterator<Item = SudokuNumber> {
-        self.numbers
-            .into_iter()
-            .enumerate()
-            .filter(|(_, available)| *available)
-            .map(|(index, _)| (index + 1).try_into().unwrap())
+        Bits(self.bits)
     }
 
     pub fn set_number(&mut self, number: SudokuNumber) {
-        self.numbers[number.to_index()] = true;
+        self.bits |= number.bit();
     }
 
     pub fn set_numbers(&mut self, numbers: impl Iterator<Item = SudokuNumber>) {
@@ -136,7 +142,7 @@ impl SudokuNumbers {
     }
 
     pub fn del_number(&mut self, number: SudokuNumber) {
-        self.numbers[number.to_index()] = false;
+        self.bits &= !number.bit();
     }
 
     pub fn del_numbers(&mut self, numbers: impl Iterator<Item = SudokuNumber>) {
@@ -146,19 +152,104 @@ impl SudokuNumbers {
     }
 
     pub fn has_number(&self, number: SudokuNumber) -> bool {
-        self.numbers[number.to_index()]
+        self.bits & number.bit() != 0
     }
 
     pub fn count_numbers(&self) -> usize {
-        self.numbers.iter().filter(|f| **f).count()
+        self.bits.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// The number, if it's the only one.
+    pub fn single(&self) -> Option<SudokuNumber> {
+        if self.bits.is_power_of_two() {
+            self.iter().next()
+        } else {
+            None
+        }
+    }
+
+    /// Numbers in either of the sets.
+    pub fn union(&self, other: &SudokuNumbers) -> SudokuNumbers {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Numbers in both of the sets.
+    pub fn intersection(&self, other: &SudokuNumbers) -> SudokuNumbers {
+        Self {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Numbers in this set but not in `other`.
+    pub fn difference(&self, other: &SudokuNumbers) -> SudokuNumbers {
+        Self {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Whether every number of this set is also in `other`.
+    pub fn is_subset(&self, other: &SudokuNumbers) -> bool {
+        self.bits & !other.bits == 0
+    }
+
+    pub fn get_missing_numbers(&self) -> impl Iterator<Item = SudokuNumber> + use<> {
+        Bits(!self.bits & ALL_BITS)
+    }
+}
+
+impl std::ops::BitOr for SudokuNumbers {
+    type Output = SudokuNumbers;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(&rhs)
+    }
+}
+
+impl std::ops::BitOrAssign for SudokuNumbers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl std::ops::BitAnd for SudokuNumbers {
+    type Output = SudokuNumbers;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        self.intersection(&rhs)
+    }
+}
+
+impl std::ops::BitAndAssign for SudokuNumbers {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
+/// Numbers of a mask, from the lowest.
+struct Bits(u16);
+
+impl Iterator for Bits {
+    type Item = SudokuNumber;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let index = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(SudokuNumber::ALL[index])
     }
 
-    pub fn get_missing_numbers(&self) -> impl Iterator<Item = SudokuNumber> {
-        self.numbers
-            .iter()
-            .enumerate()
-            .filter(|(_, available)| !**available)
-            .map(|(index, _)| (index + 1).try_into().unwrap())
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.0.count_ones() as usize;
+        (count, Some(count))
     }
 }
 
@@ -192,4 +283,38 @@ mod tests {
         numbers.del_number(SudokuNumber::One);
         assert!(!numbers.has_number(SudokuNumber::One));
     }
+
+    #[test]
+    fn test_numbers_algebra() {
+        use SudokuNumber::*;
+
+        let first = SudokuNumbers::new([One, Two, Three]);
+        let second = SudokuNumbers::new([Three, Four]);
+
+        assert_eq!(
+            first.union(&second),
+            SudokuNumbers::new([One, Two, Three, Four])
+        );
+        assert_eq!(first | second, first.union(&second));
+        assert_eq!(first.intersection(&second), SudokuNumbers::new([Three]));
+        assert_eq!(first & second, first.intersection(&second));
+        assert_eq!(first.difference(&second), SudokuNumbers::new([One, Two]));
+
+        assert!(SudokuNumbers::new([One, Three]).is_subset(&first));
+        assert!(!second.is_subset(&first));
+        assert!(SudokuNumbers::default().is_subset(&first));
+
+        assert_eq!((first & second).single(), Some(Three));
+        assert_eq!(first.single(), None);
+        assert_eq!(SudokuNumbers::default().single(), None);
+
+        assert_eq!(
+            SudokuNumbers::new_all().difference(&first),
+            SudokuNumbers::new(first.get_missing_numbers())
+        );
+        assert_eq!(
+            SudokuNumbers::from([true, false, false, false, false, false, false, false, true]),
+            SudokuNumbers::new([One, Nine])
+        );
+    }
 }
@@ -0,0 +1,309 @@
+use std::fmt::Display;
+
+use rand::{Rng, seq::SliceRandom};
+
+use crate::{BlockIndex, SudokuBlockStatus, SudokuBoard, numbers::SudokuNumber};
+
+/// Possible reasons for a board to be unsolvable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    /// Some of the given (fixed or resolved) numbers are already conflicting with each other.
+    ConflictingGivens,
+    /// Givens are fine, but there's no way to fill the rest of the board.
+    NoSolution,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::ConflictingGivens => write!(f, "The board has conflicting givens"),
+            SolveError::NoSolution => write!(f, "The board has no solution"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+const ALL_BITS: u16 = 0b1_1111_1111;
+
+/// A compact copy of the board numbers used by the backtracking search.
+///
+/// Cells are stored row by row and `0` means the cell is empty.
+#[derive(Debug, Clone)]
+pub(crate) struct Grid {
+    cells: [u8; 81],
+    rows: [u16; 9],
+    cols: [u16; 9],
+    squares: [u16; 9],
+}
+
+impl Grid {
+    pub(crate) fn empty() -> Self {
+        Self {
+            cells: [0; 81],
+            rows: [0; 9],
+            cols: [0; 9],
+            squares: [0; 9],
+        }
+    }
+
+    /// Creates a grid using [`SudokuBlockStatus::Fixed`] and [`SudokuBlockStatus::Resolved`]
+    /// blocks as givens, everything else is considered empty.
+    pub(crate) fn from_board(board: &SudokuBoard) -> Result<Self, SolveError> {
+        let mut grid = Self::empty();
+
+        for block in board.get_blocks() {
+            let number = match block.status {
+                SudokuBlockStatus::Fixed(number) | SudokuBlockStatus::Resolved(number) => number,
+                _ => continue,
+            };
+
+            let (row, col) = block.index().actual_indexes();
+            if grid.candidates(row * 9 + col) & bit(number.to_u8()) == 0 {
+                return Err(SolveError::ConflictingGivens);
+            }
+
+            grid.place(row * 9 + col, number.to_u8());
+        }
+
+        Ok(grid)
+    }
+
+    fn candidates(&self, cell: usize) -> u16 {
+        let (row, col) = (cell / 9, cell % 9);
+        !(self.rows[row] | self.cols[col] | self.squares[square_of(row, col)]) & ALL_BITS
+    }
+
+    pub(crate) fn place(&mut self, cell: usize, number: u8) {
+        let (row, col) = (cell / 9, cell % 9);
+        self.cells[cell] = number;
+        self.rows[row] |= bit(number);
+        self.cols[col] |= bit(number);
+        self.squares[square_of(row, col)] |= bit(number);
+    }
+
+    /// Empties the cell and returns the number it had.
+    pub(crate) fn remove(&mut self, cell: usize) -> u8 {
+        let (row, col) = (cell / 9, cell % 9);
+        let number = self.cells[cell];
+        self.cells[cell] = 0;
+        self.rows[row] &= !bit(number);
+        self.cols[col] &= !bit(number);
+        self.squares[square_of(row, col)] &= !bit(number);
+        number
+    }
+
+    /// Finds the empty cell with the least candidates, `None` means the grid is full.
+    fn most_constrained_cell(&self) -> Option<(usize, u16)> {
+        let mut best: Option<(usize, u16)> = None;
+
+        for cell in (0..81).filter(|cell| self.cells[*cell] == 0) {
+            let candidates = self.candidates(cell);
+            if best.is_none_or(|(_, b)| candidates.count_ones() < b.count_ones()) {
+                best = Some((cell, candidates));
+                if candidates.count_ones() <= 1 {
+                    break;
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Searches for a solution and leaves the grid filled with it.
+    pub(crate) fn solve(mut self) -> Result<Self, SolveError> {
+        if self.search() {
+            Ok(self)
+        } else {
+            Err(SolveError::NoSolution)
+        }
+    }
+
+    fn search(&mut self) -> bool {
+        let Some((cell, candidates)) = self.most_constrained_cell() else {
+            return true;
+        };
+
+        for number in numbers_of(candidates) {
+            self.place(cell, number);
+            if self.search() {
+                return true;
+            }
+            self.remove(cell);
+        }
+
+        false
+    }
+
+    /// Fills the empty cells using a random solution, trying numbers in the order given by `rng`.
+    pub(crate) fn fill_random(&mut self, rng: &mut impl Rng) -> bool {
+        let Some((cell, candidates)) = self.most_constrained_cell() else {
+            return true;
+        };
+
+        let mut numbers = numbers_of(candidates).collect::<Vec<_>>();
+        numbers.shuffle(rng);
+
+        for number in numbers {
+            self.place(cell, number);
+            if self.fill_random(rng) {
+                return true;
+            }
+            self.remove(cell);
+        }
+
+        false
+    }
+
+    /// Counts the solutions of this grid, stops as soon as `limit` solutions are found.
+    pub(crate) fn count_solutions(&mut self, limit: usize) -> usize {
+        self.count_solutions_with_first(limit).0
+    }
+
+    /// Same as [`Grid::count_solutions`], along with the first solution found.
+    pub(crate) fn count_solutions_with_first(&mut self, limit: usize) -> (usize, Option<Grid>) {
+        let mut count = 0;
+        let mut first = None;
+        self.count(limit, &mut count, &mut first);
+        (count, first)
+    }
+
+    fn count(&mut self, limit: usize, count: &mut usize, first: &mut Option<Grid>) {
+        let Some((cell, candidates)) = self.most_constrained_cell() else {
+            *count += 1;
+            if first.is_none() {
+                *first = Some(self.clone());
+            }
+            return;
+        };
+
+        for number in numbers_of(candidates) {
+            self.place(cell, number);
+            self.count(limit, count, first);
+            self.remove(cell);
+
+            if *count >= limit {
+                return;
+            }
+        }
+    }
+
+    pub(crate) fn to_u8(&self) -> [[Option<u8>; 9]; 9] {
+        let mut numbers = [[None; 9]; 9];
+        for (cell, number) in self.cells.iter().enumerate() {
+            if *number != 0 {
+                numbers[cell / 9][cell % 9] = Some(*number);
+            }
+        }
+        numbers
+    }
+
+    pub(crate) fn number_at(&self, index: &BlockIndex) -> Option<SudokuNumber> {
+        let (row, col) = index.actual_indexes();
+        (self.cells[row * 9 + col] as usize).try_into().ok()
+    }
+}
+
+fn bit(number: u8) -> u16 {
+    1 << (number - 1)
+}
+
+fn numbers_of(bits: u16) -> impl Iterator<Item = u8> {
+    (1..=9).filter(move |number| bits & bit(*number) != 0)
+}
+
+fn square_of(row: usize, col: usize) -> usize {
+    (row / 3) * 3 + col / 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_samples() {
+        for sample in [sudoku_samples::easy::FIRST, sudoku_samples::normal::FIRST] {
+            let board = SudokuBoard::from_u8(sample);
+            let solution = board.solution().unwrap();
+
+            assert!(solution.verify_board());
+            assert!(
+                solution
+                    .get_blocks()
+                    .all(|f| f.is_fixed() || f.is_resolved())
+            );
+            assert!(
+                board
+                    .get_blocks()
+                    .filter(|f| f.is_fixed())
+                    .all(|f| solution.get_block(f.index()) == f)
+            );
+        }
+    }
+
+    #[test]
+    fn test_solve_in_place() {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::normal::FIRST);
+        board.solve().unwrap();
+
+        assert!(board.verify_board());
+        assert_eq!(board.get_blocks().filter(|f| f.is_resolved()).count(), 51);
+    }
+
+    #[test]
+    fn test_conflicting_givens() {
+        let mut numbers = sudoku_samples::normal::FIRST;
+        numbers[0][2] = Some(5);
+
+        let board = SudokuBoard::from_u8(numbers);
+        assert_eq!(board.solution(), Err(SolveError::ConflictingGivens));
+    }
+
+    #[test]
+    fn test_no_solution() {
+        // Nothing conflicts directly, but the first block can't hold anything.
+        let mut numbers = [[None; 9]; 9];
+        numbers[0][1..9].copy_from_slice(&[
+            Some(1),
+            Some(2),
+            Some(3),
+            Some(4),
+            Some(5),
+            Some(6),
+            Some(7),
+            Some(8),
+        ]);
+        numbers[1][0] = Some(9);
+
+        let mut board = SudokuBoard::from_u8(numbers);
+        let before = board.clone();
+
+        assert_eq!(board.solve(), Err(SolveError::NoSolution));
+        assert_eq!(board, before);
+        assert_eq!(board.count_solutions(10), 0);
+    }
+
+    #[test]
+    fn test_samples_are_unique() {
+        for sample in [sudoku_samples::easy::FIRST, sudoku_samples::normal::FIRST] {
+            let board = SudokuBoard::from_u8(sample);
+
+            assert_eq!(board.count_solutions(2), 1);
+            assert!(board.is_uniquely_solvable());
+        }
+    }
+
+    #[test]
+    fn test_count_solutions_limit() {
+        let board = SudokuBoard::default();
+
+        assert_eq!(board.count_solutions(0), 0);
+        assert_eq!(board.count_solutions(5), 5);
+        assert!(!board.is_uniquely_solvable());
+
+        let solved = SudokuBoard::from_u8(sudoku_samples::easy::FIRST)
+            .solution()
+            .unwrap();
+        assert_eq!(solved.count_solutions(5), 1);
+    }
+}
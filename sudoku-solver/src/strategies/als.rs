@@ -0,0 +1,359 @@
+//! Almost locked sets, `n` blocks of a container having `n + 1` possible numbers together.
+//! Removing any one of the numbers leaves the rest locked in the set, like a naked subset.
+
+use std::collections::HashSet;
+
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::{SudokuNumber, SudokuNumbers},
+    strategies::{
+        SolveStep, Strategy, SudokuSolvingStrategy, common_peers_having, possible_blocks,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlmostLockedSet {
+    pub container: (ContainerType, SudokuNumber),
+    pub blocks: Vec<(BlockIndex, SudokuNumbers)>,
+    /// Every possible number of the blocks, one more than the blocks count.
+    pub numbers: SudokuNumbers,
+}
+
+impl AlmostLockedSet {
+    pub fn contains(&self, index: &BlockIndex) -> bool {
+        self.blocks.iter().any(|(f, _)| f == index)
+    }
+
+    /// Blocks of the set that can be `number`.
+    pub fn places_of(&self, number: SudokuNumber) -> impl Iterator<Item = &BlockIndex> {
+        self.blocks
+            .iter()
+            .filter(move |(_, numbers)| numbers.has_number(number))
+            .map(|(index, _)| index)
+    }
+
+    fn overlaps(&self, other: &AlmostLockedSet) -> bool {
+        self.blocks.iter().any(|(f, _)| other.contains(f))
+    }
+
+    /// Every possible number of every block of the set.
+    fn sources(&self) -> impl Iterator<Item = (BlockIndex, SudokuNumber)> + '_ {
+        self.blocks
+            .iter()
+            .flat_map(|(index, numbers)| numbers.iter().map(move |f| (index.clone(), f)))
+    }
+}
+
+/// Finds every almost locked set in rows, columns and squares. A set that's in more than
+/// one container is only listed once.
+pub fn almost_locked_sets(board: &SudokuBoard) -> Vec<AlmostLockedSet> {
+    let mut sets: Vec<AlmostLockedSet> = vec![];
+    // Positions of the blocks of every listed set, sorted.
+    let mut listed: HashSet<Vec<usize>> = HashSet::new();
+
+    for container_type in ContainerType::ALL {
+        for container_number in SudokuNumber::ALL {
+            let blocks = board
+                .iter_container(container_type, container_number)
+                .filter_map(|f| {
+                    f.status
+                        .as_possibilities()
+                        .map(|p| (f.index().clone(), p.numbers))
+                })
+                .collect::<Vec<_>>();
+
+            let mut found = vec![];
+            grow_sets(
+                &mut vec![],
+                SudokuNumbers::default(),
+                &blocks,
+                &mut |subset, numbers| found.push((subset.to_vec(), numbers)),
+            );
+            // Smaller sets first.
+            found.sort_by_key(|(subset, _)| subset.len());
+
+            for (subset, numbers) in found {
+                let mut positions = subset
+                    .iter()
+                    .map(|(index, _)| index.position())
+                    .collect::<Vec<_>>();
+                positions.sort_unstable();
+                if !listed.insert(positions) {
+                    continue;
+                }
+
+                sets.push(AlmostLockedSet {
+                    container: (container_type, container_number),
+                    blocks: subset,
+                    numbers,
+                });
+            }
+        }
+    }
+
+    sets
+}
+
+/// Adds blocks of `rest` to `subset` in order, calling `found` for every almost locked set.
+///
+/// A subset is dropped as soon as it has more numbers than the blocks left could make up for.
+fn grow_sets(
+    subset: &mut Vec<(BlockIndex, SudokuNumbers)>,
+    numbers: SudokuNumbers,
+    rest: &[(BlockIndex, SudokuNumbers)],
+    found: &mut impl FnMut(&[(BlockIndex, SudokuNumbers)], SudokuNumbers),
+) {
+    for (position, block) in rest.iter().enumerate() {
+        let numbers = numbers | block.1;
+        let rest = &rest[position + 1..];
+        if numbers.count_numbers() > subset.len() + rest.len() + 2 {
+            continue;
+        }
+
+        subset.push(block.clone());
+        if numbers.count_numbers() == subset.len() + 1 {
+            found(subset, numbers);
+        }
+        grow_sets(subset, numbers, rest, found);
+        subset.pop();
+    }
+}
+
+/// Possible `number` outside of the sets, in blocks seeing every place of it in the sets.
+fn common_peers_of(
+    board: &SudokuBoard,
+    sets: &[&AlmostLockedSet],
+    number: SudokuNumber,
+) -> Vec<(BlockIndex, SudokuNumber)> {
+    let places = sets
+        .iter()
+        .flat_map(|f| f.places_of(number))
+        .collect::<Vec<_>>();
+    if places.is_empty() {
+        return vec![];
+    }
+
+    common_peers_having(board, &places, number)
+}
+
+/// Two sets sharing a restricted number `x` (every `x` of one set sees every `x` of the other),
+/// so only one of them can have it. The other one is locked and so `z`, another shared number,
+/// is in one of them. It's removed from blocks seeing every `z` in both sets.
+pub struct AlsXzStrategy;
+
+impl SudokuSolvingStrategy for AlsXzStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::AlsXz
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let sets = almost_locked_sets(board);
+        let mut steps = vec![];
+
+        for (i, first) in sets.iter().enumerate() {
+            for second in sets[i + 1..].iter().filter(|f| !f.overlaps(first)) {
+                let common = (first.numbers & second.numbers).iter().collect::<Vec<_>>();
+
+                for x in common.iter().copied().filter(|x| {
+                    first
+                        .places_of(*x)
+                        .all(|f| second.places_of(*x).all(|s| f.is_peer_of(s)))
+                }) {
+                    let eliminations = common
+                        .iter()
+                        .filter(|z| **z != x)
+                        .flat_map(|z| common_peers_of(board, &[first, second], *z))
+                        .collect::<Vec<_>>();
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_sources(first.sources())
+                            .with_sources(second.sources())
+                            .with_sets([first.container, second.container], [])
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// A stem block and a set (petal) for each of its possible numbers, every one of the number
+/// in the petal sees the stem. Whatever the stem is, one petal is locked, so a number `z`
+/// shared by every petal is removed from blocks seeing every `z` in the petals.
+pub struct DeathBlossomStrategy;
+
+impl SudokuSolvingStrategy for DeathBlossomStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::DeathBlossom
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let sets = almost_locked_sets(board);
+        let mut removed = HashSet::new();
+        let mut steps = vec![];
+
+        for (stem, stem_numbers) in possible_blocks(board)
+            .into_iter()
+            .filter(|(_, f)| (2..=3).contains(&f.count_numbers()))
+        {
+            let petals = stem_numbers
+                .iter()
+                .map(|number| {
+                    sets.iter()
+                        .filter(|f| !f.contains(stem) && f.numbers.has_number(number))
+                        .filter(|f| f.places_of(number).all(|f| f.is_peer_of(stem)))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            for z in stem_numbers.get_missing_numbers() {
+                let petals = petals
+                    .iter()
+                    .map(|f| {
+                        f.iter()
+                            .filter(|f| f.numbers.has_number(z))
+                            .copied()
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+
+                for blossom in blossoms(&petals) {
+                    let eliminations = common_peers_of(board, &blossom, z)
+                        .into_iter()
+                        .filter(|f| removed.insert(f.clone()))
+                        .collect::<Vec<_>>();
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_sources(stem_numbers.iter().map(|f| (stem.clone(), f)))
+                            .with_sources(blossom.iter().flat_map(|f| f.sources()))
+                            .with_sets(blossom.iter().map(|f| f.container), [])
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// Every way of choosing one petal for each number, without petals overlapping.
+fn blossoms<'s>(petals: &[Vec<&'s AlmostLockedSet>]) -> Vec<Vec<&'s AlmostLockedSet>> {
+    let Some((first, rest)) = petals.split_first() else {
+        return vec![vec![]];
+    };
+
+    let rest = blossoms(rest);
+    first
+        .iter()
+        .flat_map(|petal| {
+            rest.iter()
+                .filter(|f| f.iter().all(|f| !f.overlaps(petal)))
+                .map(|f| {
+                    let mut blossom = vec![*petal];
+                    blossom.extend(f.iter().copied());
+                    blossom
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::strategies::test_boards::board_with_blocks;
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_almost_locked_sets() {
+        let board = board_with_blocks(&[
+            (BlockIndex::new(One, One), &[One, Two]),
+            (BlockIndex::new(One, Two), &[One, Three]),
+        ]);
+        let sets = almost_locked_sets(&board);
+
+        // Both in row 1 and square 1, listed only once.
+        let pair = sets
+            .iter()
+            .filter(|f| {
+                f.blocks.len() == 2
+                    && f.contains(&BlockIndex::new(One, One))
+                    && f.contains(&BlockIndex::new(One, Two))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(pair.len(), 1);
+        assert_eq!(pair[0].container, (ContainerType::Row, One));
+        assert_eq!(pair[0].numbers, SudokuNumbers::new([One, Two, Three]));
+        assert_eq!(
+            pair[0].places_of(Two).collect::<Vec<_>>(),
+            vec![&BlockIndex::new(One, One)]
+        );
+
+        // A block with two possible numbers is a set by itself.
+        assert!(
+            sets.iter()
+                .any(|f| f.blocks.len() == 1 && f.contains(&BlockIndex::new(One, One)))
+        );
+    }
+
+    #[test]
+    fn test_als_xz() {
+        // {1, 2} in r1c1 and {1, 3}, {2, 3} in column 5, 1 is restricted and 2 is removed
+        // from r5c1.
+        let board = board_with_blocks(&[
+            (BlockIndex::new(One, One), &[One, Two]),
+            (BlockIndex::new(One, Five), &[One, Three]),
+            (BlockIndex::new(Five, Five), &[Two, Three]),
+            (BlockIndex::new(Five, One), &[Two, Four]),
+        ]);
+
+        let steps = AlsXzStrategy.find_steps(&board);
+        let step = steps
+            .iter()
+            .find(|f| f.eliminations == vec![(BlockIndex::new(Five, One), Two)])
+            .unwrap();
+        assert_eq!(
+            step.source_blocks(),
+            vec![
+                BlockIndex::new(One, One),
+                BlockIndex::new(One, Five),
+                BlockIndex::new(Five, Five)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_death_blossom() {
+        // Stem r5c5 {1, 2}, petals r5c1 {1, 3} and r1c5 {2, 3}, 3 is removed from r1c1.
+        let board = board_with_blocks(&[
+            (BlockIndex::new(Five, Five), &[One, Two]),
+            (BlockIndex::new(Five, One), &[One, Three]),
+            (BlockIndex::new(One, Five), &[Two, Three]),
+            (BlockIndex::new(One, One), &[Three, Four]),
+        ]);
+
+        let steps = DeathBlossomStrategy.find_steps(&board);
+        let step = steps
+            .iter()
+            .find(|f| f.eliminations == vec![(BlockIndex::new(One, One), Three)])
+            .unwrap();
+        assert_eq!(step.sources[0], (BlockIndex::new(Five, Five), One));
+        assert_eq!(
+            step.base_sets,
+            vec![(ContainerType::Row, Five), (ContainerType::Row, One)]
+        );
+    }
+}
@@ -0,0 +1,405 @@
+//! Alternating inference chains, possible numbers connected by links alternating between strong
+//! ones (at least one of the two is true) and weak ones (at most one of the two is true).
+//!
+//! A chain starting and ending with strong links means one of its ends is true,
+//! so possible numbers seeing both ends are removed.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Write as _},
+};
+
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::SudokuNumber,
+    strategies::{
+        SolveStep, Strategy, SudokuSolvingStrategy, conjugate_pairs, possible_blocks, sees,
+    },
+};
+
+/// Longest chain to look for, in links.
+const MAX_LINKS: usize = 11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinkType {
+    /// At least one of the two is true.
+    Strong,
+    /// At most one of the two is true.
+    Weak,
+}
+
+impl Display for LinkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkType::Strong => f.write_char('='),
+            LinkType::Weak => f.write_char('-'),
+        }
+    }
+}
+
+/// Possible numbers in the order they're linked, `links[i]` connects `nodes[i]` and `nodes[i + 1]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chain {
+    pub nodes: Vec<(BlockIndex, SudokuNumber)>,
+    pub links: Vec<LinkType>,
+}
+
+impl Display for Chain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (index, number)) in self.nodes.iter().enumerate() {
+            if let Some(link) = i.checked_sub(1).and_then(|f| self.links.get(f)) {
+                write!(f, " {} ", link)?;
+            }
+            write!(f, "{}({})", index, number.to_u8())?;
+        }
+        Ok(())
+    }
+}
+
+/// Strong and weak links between every possible number of a board.
+#[derive(Debug, Clone)]
+pub struct LinkGraph {
+    nodes: Vec<(BlockIndex, SudokuNumber)>,
+    strong: Vec<Vec<usize>>,
+    weak: Vec<Vec<usize>>,
+}
+
+impl LinkGraph {
+    pub fn new(board: &SudokuBoard) -> Self {
+        let nodes = possible_blocks(board)
+            .into_iter()
+            .flat_map(|(index, numbers)| numbers.iter().map(move |f| (index.clone(), f)))
+            .collect::<Vec<_>>();
+        let ids = nodes
+            .iter()
+            .enumerate()
+            .map(|(id, node)| (node, id))
+            .collect::<HashMap<_, _>>();
+
+        let mut strong = vec![vec![]; nodes.len()];
+        let mut link = |first: usize, second: usize| {
+            if !strong[first].contains(&second) {
+                strong[first].push(second);
+                strong[second].push(first);
+            }
+        };
+
+        // A number with only two places in a container.
+        for number in SudokuNumber::ALL {
+            for container_type in ContainerType::ALL {
+                for pair in conjugate_pairs(board, container_type, number) {
+                    let [first, second] = pair.blocks;
+                    link(ids[&(first, number)], ids[&(second, number)]);
+                }
+            }
+        }
+
+        // A block with only two possible numbers.
+        for (index, numbers) in possible_blocks(board) {
+            let mut numbers = numbers.iter();
+            if let (Some(first), Some(second), None) =
+                (numbers.next(), numbers.next(), numbers.next())
+            {
+                link(ids[&(index.clone(), first)], ids[&(index.clone(), second)]);
+            }
+        }
+
+        let mut weak = vec![vec![]; nodes.len()];
+        for (first, (first_index, first_number)) in nodes.iter().enumerate() {
+            for (second, (second_index, second_number)) in nodes.iter().enumerate().skip(first + 1)
+            {
+                if sees((first_index, *first_number), (second_index, *second_number)) {
+                    weak[first].push(second);
+                    weak[second].push(first);
+                }
+            }
+        }
+
+        Self {
+            nodes,
+            strong,
+            weak,
+        }
+    }
+
+    /// Every possible number of the board.
+    pub fn nodes(&self) -> &[(BlockIndex, SudokuNumber)] {
+        &self.nodes
+    }
+
+    /// Possible numbers linked to `node`, strong links are weak links as well.
+    pub fn links(
+        &self,
+        node: &(BlockIndex, SudokuNumber),
+        link_type: LinkType,
+    ) -> Vec<&(BlockIndex, SudokuNumber)> {
+        match self.nodes.iter().position(|f| f == node) {
+            Some(id) => self
+                .neighbors(id, link_type)
+                .iter()
+                .map(|f| &self.nodes[*f])
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    fn neighbors(&self, id: usize, link_type: LinkType) -> &[usize] {
+        match link_type {
+            LinkType::Strong => &self.strong[id],
+            LinkType::Weak => &self.weak[id],
+        }
+    }
+
+    /// Possible numbers seeing both nodes, the weak links are sorted.
+    fn common_peers(&self, first: usize, second: usize) -> Vec<usize> {
+        self.weak[first]
+            .iter()
+            .filter(|f| self.weak[second].binary_search(f).is_ok())
+            .copied()
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ChainKind {
+    /// A single number, linked through containers.
+    X,
+    /// Blocks with two possible numbers, linked to each other through a shared number.
+    XY,
+    /// Any links.
+    Any,
+}
+
+/// Searches alternating inference chains, from the shortest ones.
+pub struct ChainStrategy {
+    kind: ChainKind,
+}
+
+impl ChainStrategy {
+    pub const X_CHAIN: ChainStrategy = ChainStrategy { kind: ChainKind::X };
+    pub const XY_CHAIN: ChainStrategy = ChainStrategy {
+        kind: ChainKind::XY,
+    };
+    pub const AIC: ChainStrategy = ChainStrategy {
+        kind: ChainKind::Any,
+    };
+
+    fn allows(&self, graph: &LinkGraph, from: usize, to: usize, link_type: LinkType) -> bool {
+        let (from, to) = (&graph.nodes[from], &graph.nodes[to]);
+        match (self.kind, link_type) {
+            (ChainKind::X, _) => from.1 == to.1,
+            (ChainKind::XY, LinkType::Strong) => from.0 == to.0,
+            (ChainKind::XY, LinkType::Weak) => from.0 != to.0 && from.1 == to.1,
+            (ChainKind::Any, _) => true,
+        }
+    }
+
+    /// Chains starting from `start` and every possible number they remove, one per end.
+    fn chains_from(&self, graph: &LinkGraph, start: usize) -> Vec<(Vec<usize>, Vec<usize>)> {
+        // Every node reached, with the one it was reached from and the chain length.
+        let mut reached: Vec<(usize, Option<usize>, usize)> = vec![(start, None, 0)];
+        let mut visited = HashSet::from([(start, LinkType::Weak)]);
+        let mut chains = vec![];
+
+        let mut next = 0;
+        while let Some((node, _, length)) = reached.get(next).copied() {
+            let parent = next;
+            next += 1;
+            if length == MAX_LINKS {
+                continue;
+            }
+
+            let link_type = if length % 2 == 0 {
+                LinkType::Strong
+            } else {
+                LinkType::Weak
+            };
+
+            for &to in graph.neighbors(node, link_type) {
+                if !self.allows(graph, node, to, link_type) || !visited.insert((to, link_type)) {
+                    continue;
+                }
+
+                reached.push((to, Some(parent), length + 1));
+                if link_type == LinkType::Weak || length + 1 < 3 || to == start {
+                    continue;
+                }
+
+                let eliminations = graph.common_peers(start, to);
+                if eliminations.is_empty() {
+                    continue;
+                }
+
+                let mut path = vec![];
+                let mut current = Some(reached.len() - 1);
+                while let Some(at) = current {
+                    path.push(reached[at].0);
+                    current = reached[at].1;
+                }
+                path.reverse();
+
+                // A chain going through the same possible number twice is only a loop.
+                let unique = path.iter().collect::<HashSet<_>>().len() == path.len();
+                if unique {
+                    chains.push((path, eliminations));
+                }
+            }
+        }
+
+        chains
+    }
+}
+
+impl SudokuSolvingStrategy for ChainStrategy {
+    fn strategy(&self) -> Strategy {
+        match self.kind {
+            ChainKind::X => Strategy::XChain,
+            ChainKind::XY => Strategy::XYChain,
+            ChainKind::Any => Strategy::AlternatingInferenceChain,
+        }
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let graph = LinkGraph::new(board);
+        let mut removed = HashSet::new();
+        let mut steps = vec![];
+
+        for start in 0..graph.nodes.len() {
+            for (path, eliminations) in self.chains_from(&graph, start) {
+                let eliminations = eliminations
+                    .into_iter()
+                    .filter(|f| removed.insert(*f))
+                    .map(|f| graph.nodes[f].clone())
+                    .collect::<Vec<_>>();
+                if eliminations.is_empty() {
+                    continue;
+                }
+
+                let chain = Chain {
+                    nodes: path.iter().map(|f| graph.nodes[*f].clone()).collect(),
+                    links: (1..path.len())
+                        .map(|f| match f % 2 {
+                            1 => LinkType::Strong,
+                            _ => LinkType::Weak,
+                        })
+                        .collect(),
+                };
+
+                steps.push(
+                    SolveStep::new(self.strategy())
+                        .with_sources(chain.nodes.iter().cloned())
+                        .with_chain(chain)
+                        .with_eliminations(eliminations),
+                );
+            }
+        }
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{numbers::SudokuNumbers, strategies::test_boards::board_with};
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_link_graph() {
+        // Row 1 has 1 in columns 1 and 5, r1c1 is {1, 2}.
+        let board = board_with(|f| match (f.row, f.col) {
+            (One, One) => SudokuNumbers::new([One, Two]),
+            (One, Five) => SudokuNumbers::new_all(),
+            (One, _) => SudokuNumbers::new([Two, Three, Four, Five, Six, Seven, Eight, Nine]),
+            _ => SudokuNumbers::new_all(),
+        });
+        let graph = LinkGraph::new(&board);
+
+        let node = (BlockIndex::new(One, One), One);
+        assert_eq!(
+            graph.links(&node, LinkType::Strong),
+            vec![
+                &(BlockIndex::new(One, Five), One),
+                &(BlockIndex::new(One, One), Two)
+            ]
+        );
+
+        let weak = graph.links(&node, LinkType::Weak);
+        assert!(weak.contains(&&(BlockIndex::new(One, Five), One)));
+        assert!(weak.contains(&&(BlockIndex::new(One, One), Two)));
+        assert!(weak.contains(&&(BlockIndex::new(Nine, One), One)));
+        assert!(!weak.contains(&&(BlockIndex::new(Nine, Nine), One)));
+    }
+
+    #[test]
+    fn test_x_chain() {
+        // 1 in row 1 is in c1 and c4, in column 4 in r1 and r5, in row 5 in c4 and c2.
+        let board = board_with(|f| {
+            let mut numbers = SudokuNumbers::new_all();
+            let has_one = match (f.row, f.col) {
+                (One, col) => [One, Four].contains(&col),
+                (Five, col) => [Two, Four].contains(&col),
+                (row, Four) => [One, Five].contains(&row),
+                _ => true,
+            };
+            if !has_one {
+                numbers.del_number(One);
+            }
+            numbers
+        });
+
+        let steps = ChainStrategy::X_CHAIN.find_steps(&board);
+        let step = steps
+            .iter()
+            .find(|f| {
+                f.chain
+                    .as_ref()
+                    .is_some_and(|f| f.nodes.last() == Some(&(BlockIndex::new(Five, Two), One)))
+            })
+            .unwrap();
+
+        assert_eq!(
+            step.chain.as_ref().unwrap().to_string(),
+            "r1c1(1) = r1c4(1) - r5c4(1) = r5c2(1)"
+        );
+        assert_eq!(
+            step.eliminations,
+            vec![
+                (BlockIndex::new(Two, Two), One),
+                (BlockIndex::new(Three, Two), One),
+                (BlockIndex::new(Four, One), One),
+                (BlockIndex::new(Six, One), One)
+            ]
+        );
+        assert!(
+            step.to_string()
+                .starts_with("X-Chain: r1c1(1) = r1c4(1) - r5c4(1) = r5c2(1), removes r2c2(1)")
+        );
+    }
+
+    #[test]
+    fn test_xy_chain() {
+        // r1c1 {1, 2} - r1c5 {2, 3} - r5c5 {3, 1}, r5c1 sees both ends and can't be 1.
+        let board = board_with(|f| match (f.row, f.col) {
+            (One, One) => SudokuNumbers::new([One, Two]),
+            (One, Five) => SudokuNumbers::new([Two, Three]),
+            (Five, Five) => SudokuNumbers::new([Three, One]),
+            (Five, One) => SudokuNumbers::new([One, Four, Five]),
+            _ => SudokuNumbers::new([Four, Five, Six, Seven, Eight, Nine]),
+        });
+
+        let steps = ChainStrategy::XY_CHAIN.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].eliminations,
+            vec![(BlockIndex::new(Five, One), One)]
+        );
+        assert_eq!(
+            steps[0].chain.as_ref().unwrap().to_string(),
+            "r1c1(1) = r1c1(2) - r1c5(2) = r1c5(3) - r5c5(3) = r5c5(1)"
+        );
+        assert!(ChainStrategy::X_CHAIN.find_steps(&board).is_empty());
+        assert!(!ChainStrategy::AIC.find_steps(&board).is_empty());
+    }
+}
@@ -0,0 +1,406 @@
+//! Coloring strategies, chains of strong links painted with two alternating colors.
+//!
+//! Exactly one of the colors of a cluster is the truth: every candidate of that color is the
+//! answer of its block and every candidate of the other color is not.
+
+use std::collections::HashMap;
+
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::SudokuNumber,
+    strategies::{
+        SolveStep, Strategy, SudokuSolvingStrategy, conjugate_pairs, possible_blocks, sees,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CandidateColor {
+    First,
+    Second,
+}
+
+impl CandidateColor {
+    pub fn opposite(&self) -> CandidateColor {
+        match self {
+            CandidateColor::First => CandidateColor::Second,
+            CandidateColor::Second => CandidateColor::First,
+        }
+    }
+}
+
+/// Candidates connected by strong links, each painted with the opposite color of its neighbors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorCluster {
+    pub candidates: Vec<(BlockIndex, SudokuNumber, CandidateColor)>,
+}
+
+impl ColorCluster {
+    pub fn color_of(&self, index: &BlockIndex, number: SudokuNumber) -> Option<CandidateColor> {
+        self.candidates
+            .iter()
+            .find(|(i, n, _)| i == index && *n == number)
+            .map(|(_, _, color)| *color)
+    }
+
+    pub fn of_color(
+        &self,
+        color: CandidateColor,
+    ) -> impl Iterator<Item = (&BlockIndex, SudokuNumber)> {
+        self.candidates
+            .iter()
+            .filter(move |(_, _, c)| *c == color)
+            .map(|(index, number, _)| (index, *number))
+    }
+
+    /// A color that can't be true. Either it sees itself (color wrap), or with `emptying`,
+    /// every possible number of an uncolored block sees it (cell emptied by color).
+    fn false_color(&self, board: &SudokuBoard, emptying: bool) -> Option<CandidateColor> {
+        let colors = [CandidateColor::First, CandidateColor::Second];
+
+        let wrapped = colors.into_iter().find(|color| {
+            let colored = self.of_color(*color).collect::<Vec<_>>();
+            colored
+                .iter()
+                .enumerate()
+                .any(|(i, first)| colored[i + 1..].iter().any(|second| sees(*first, *second)))
+        });
+        if wrapped.is_some() || !emptying {
+            return wrapped;
+        }
+
+        let uncolored = possible_blocks(board)
+            .into_iter()
+            .filter(|(index, _)| !self.candidates.iter().any(|(f, _, _)| f == *index))
+            .collect::<Vec<_>>();
+        colors.into_iter().find(|color| {
+            uncolored.iter().any(|(index, numbers)| {
+                numbers
+                    .iter()
+                    .all(|number| self.of_color(*color).any(|f| sees((index, number), f)))
+            })
+        })
+    }
+
+    /// Possible numbers outside of this cluster that can't be true whatever color is true,
+    /// or every candidate of a color if it can't be true.
+    fn eliminations(&self, board: &SudokuBoard, emptying: bool) -> Vec<(BlockIndex, SudokuNumber)> {
+        if let Some(color) = self.false_color(board, emptying) {
+            return self
+                .of_color(color)
+                .map(|(index, number)| (index.clone(), number))
+                .collect();
+        }
+
+        // Color trap: an uncolored candidate that sees both colors.
+        possible_blocks(board)
+            .into_iter()
+            .flat_map(|(index, numbers)| numbers.iter().map(move |number| (index, number)))
+            .filter(|(index, number)| self.color_of(index, *number).is_none())
+            .filter(|candidate| {
+                [CandidateColor::First, CandidateColor::Second]
+                    .iter()
+                    .all(|color| self.of_color(*color).any(|f| sees(*candidate, f)))
+            })
+            .map(|(index, number)| (index.clone(), number))
+            .collect()
+    }
+}
+
+type Candidate = (BlockIndex, SudokuNumber);
+
+/// Paints every connected group of candidates with two colors.
+fn color_clusters(links: Vec<(Candidate, Candidate)>) -> Vec<ColorCluster> {
+    let mut neighbors: HashMap<&Candidate, Vec<&Candidate>> = HashMap::new();
+    let mut order: Vec<&Candidate> = vec![];
+    for (first, second) in &links {
+        for (from, to) in [(first, second), (second, first)] {
+            if !neighbors.contains_key(from) {
+                order.push(from);
+            }
+            neighbors.entry(from).or_default().push(to);
+        }
+    }
+
+    let mut colors: HashMap<&Candidate, CandidateColor> = HashMap::new();
+    let mut clusters = vec![];
+    for start in order {
+        if colors.contains_key(start) {
+            continue;
+        }
+
+        let mut cluster = vec![(start, CandidateColor::First)];
+        colors.insert(start, CandidateColor::First);
+        let mut next = 0;
+        while let Some((candidate, color)) = cluster.get(next).copied() {
+            next += 1;
+            for neighbor in &neighbors[candidate] {
+                if !colors.contains_key(neighbor) {
+                    colors.insert(neighbor, color.opposite());
+                    cluster.push((neighbor, color.opposite()));
+                }
+            }
+        }
+
+        clusters.push(ColorCluster {
+            candidates: cluster
+                .into_iter()
+                .map(|((index, number), color)| (index.clone(), *number, color))
+                .collect(),
+        });
+    }
+
+    clusters
+}
+
+fn strong_links(board: &SudokuBoard, number: SudokuNumber) -> Vec<(Candidate, Candidate)> {
+    ContainerType::ALL
+        .into_iter()
+        .flat_map(|f| conjugate_pairs(board, f, number))
+        .map(|f| {
+            let [first, second] = f.blocks;
+            ((first, number), (second, number))
+        })
+        .collect()
+}
+
+/// Colors conjugate pairs of a single number.
+pub fn simple_coloring(board: &SudokuBoard, number: SudokuNumber) -> Vec<ColorCluster> {
+    color_clusters(strong_links(board, number))
+}
+
+/// Colors conjugate pairs of every number, connected together through blocks having only two
+/// possible numbers.
+pub fn medusa_coloring(board: &SudokuBoard) -> Vec<ColorCluster> {
+    let mut links = SudokuNumber::ALL
+        .into_iter()
+        .flat_map(|number| strong_links(board, number))
+        .collect::<Vec<_>>();
+
+    for (index, numbers) in possible_blocks(board) {
+        let mut numbers = numbers.iter();
+        if let (Some(first), Some(second), None) = (numbers.next(), numbers.next(), numbers.next())
+        {
+            links.push(((index.clone(), first), (index.clone(), second)));
+        }
+    }
+
+    color_clusters(links)
+}
+
+/// Steps of every cluster that can remove something, `emptying` enables the cell emptied rule
+/// which only makes sense when the cluster has more than one number.
+fn cluster_steps(
+    board: &SudokuBoard,
+    strategy: Strategy,
+    clusters: Vec<ColorCluster>,
+    emptying: bool,
+) -> Vec<SolveStep> {
+    clusters
+        .into_iter()
+        .filter_map(|cluster| {
+            let eliminations = cluster.eliminations(board, emptying);
+            if eliminations.is_empty() {
+                return None;
+            }
+
+            Some(
+                SolveStep::new(strategy)
+                    .with_sources(
+                        cluster
+                            .candidates
+                            .iter()
+                            .map(|(index, number, _)| (index.clone(), *number)),
+                    )
+                    .with_coloring(cluster.candidates)
+                    .with_eliminations(eliminations),
+            )
+        })
+        .collect()
+}
+
+pub struct SimpleColoringStrategy;
+
+impl SudokuSolvingStrategy for SimpleColoringStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::SimpleColoring
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        SudokuNumber::ALL
+            .into_iter()
+            .flat_map(|number| {
+                cluster_steps(
+                    board,
+                    self.strategy(),
+                    simple_coloring(board, number),
+                    false,
+                )
+            })
+            .collect()
+    }
+}
+
+pub struct MedusaStrategy;
+
+impl SudokuSolvingStrategy for MedusaStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::Medusa
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        cluster_steps(board, self.strategy(), medusa_coloring(board), true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        numbers::SudokuNumbers,
+        strategies::test_boards::{board_with, board_with_number},
+    };
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_color_trap() {
+        // Row 1 has 1 in columns 1 and 5, column 5 in rows 1 and 6,
+        // square 5 in r6c5 and r4c6. r4c1 sees both r1c1 and r4c6.
+        let board = board_with_number(One, |f| match (f.row, f.col, f.square_number()) {
+            (One, col, _) => [One, Five].contains(&col),
+            (row, Five, _) => [One, Six].contains(&row),
+            (_, _, Five) => *f == BlockIndex::new(Six, Five) || *f == BlockIndex::new(Four, Six),
+            _ => true,
+        });
+
+        let clusters = simple_coloring(&board, One);
+        assert_eq!(clusters.len(), 1);
+
+        let cluster = &clusters[0];
+        let color = cluster.color_of(&BlockIndex::new(One, One), One).unwrap();
+        assert_eq!(
+            cluster.color_of(&BlockIndex::new(Six, Five), One),
+            Some(color)
+        );
+        assert_eq!(
+            cluster.color_of(&BlockIndex::new(Four, Six), One),
+            Some(color.opposite())
+        );
+        assert_eq!(cluster.color_of(&BlockIndex::new(Four, One), One), None);
+
+        let steps = SimpleColoringStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].coloring.len(), 4);
+        assert_eq!(
+            steps[0].eliminations,
+            vec![(BlockIndex::new(Four, One), One)]
+        );
+    }
+
+    #[test]
+    fn test_color_wrap() {
+        // r1c1 - r1c5 - r5c5 - r5c2 - r2c2, both ends have the same color in square 1.
+        let board = board_with_number(One, |f| match (f.row, f.col) {
+            (One, col) => [One, Five].contains(&col),
+            (Five, col) => [Two, Five].contains(&col),
+            (row, Five) => [One, Five].contains(&row),
+            (row, Two) => [Two, Five].contains(&row),
+            _ => true,
+        });
+
+        let steps = SimpleColoringStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].eliminations,
+            vec![
+                (BlockIndex::new(One, One), One),
+                (BlockIndex::new(Five, Five), One),
+                (BlockIndex::new(Two, Two), One)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_medusa() {
+        // r1c1 {1, 2} and r1c5 {1, 3} are linked through 1 in row 1,
+        // 2 in column 1 is in r1c1 and r5c1, 3 in column 5 is in r1c5 and r5c5.
+        let board = board_with(|f| match (f.row, f.col) {
+            (One, One) => SudokuNumbers::new([One, Two]),
+            (One, Five) => SudokuNumbers::new([One, Three]),
+            (One, _) => SudokuNumbers::new([Two, Three, Four, Five, Six, Seven, Eight, Nine]),
+            (Five, One) | (Five, Five) => SudokuNumbers::new_all(),
+            (_, One) => SudokuNumbers::new([One, Three, Four, Five, Six, Seven, Eight, Nine]),
+            (_, Five) => SudokuNumbers::new([One, Two, Four, Five, Six, Seven, Eight, Nine]),
+            _ => SudokuNumbers::new_all(),
+        });
+        assert!(SimpleColoringStrategy.find_steps(&board).is_empty());
+
+        let clusters = medusa_coloring(&board);
+        assert_eq!(clusters.len(), 1);
+
+        let cluster = &clusters[0];
+        let color = cluster.color_of(&BlockIndex::new(One, One), One).unwrap();
+        assert_eq!(
+            cluster.color_of(&BlockIndex::new(One, One), Two),
+            Some(color.opposite())
+        );
+        assert_eq!(
+            cluster.color_of(&BlockIndex::new(Five, One), Two),
+            Some(color)
+        );
+        assert_eq!(
+            cluster.color_of(&BlockIndex::new(Five, Five), Three),
+            Some(color.opposite())
+        );
+
+        // r5c1 is 2 or r5c5 is 3, they see each other on row 5.
+        let steps = MedusaStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].eliminations,
+            vec![
+                (BlockIndex::new(Five, One), Three),
+                (BlockIndex::new(Five, Five), Two)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_medusa_cell_emptied() {
+        // 3 and 4 of r1c1, 1 and 4 of r2c9, 2 and 4 of r9c1 are linked, 4 in row 1 and
+        // column 1 and 2 in row 9 are only possible in two blocks.
+        let board = board_with(|f| match (f.row, f.col) {
+            (One, One) => SudokuNumbers::new([Three, Four]),
+            (Two, Nine) => SudokuNumbers::new([One, Four]),
+            (Nine, One) => SudokuNumbers::new([Two, Four]),
+            (Two, Two) => SudokuNumbers::new([One, Two, Three]),
+            (One, Nine) => SudokuNumbers::new_all(),
+            (Nine, Nine) => SudokuNumbers::new([One, Three, Five, Six, Seven, Eight, Nine]),
+            (One, _) | (_, One) | (_, Nine) => {
+                SudokuNumbers::new([One, Two, Three, Five, Six, Seven, Eight, Nine])
+            }
+            (Nine, _) if f.col != Two => {
+                SudokuNumbers::new([One, Three, Four, Five, Six, Seven, Eight, Nine])
+            }
+            _ => SudokuNumbers::new_all(),
+        });
+
+        let clusters = medusa_coloring(&board);
+        assert_eq!(clusters.len(), 1);
+        let cluster = &clusters[0];
+        let color = cluster.color_of(&BlockIndex::new(One, One), Three).unwrap();
+        assert_eq!(cluster.color_of(&BlockIndex::new(Two, Two), One), None);
+
+        // Whatever r2c2 is, it sees 3 of r1c1, 1 of r2c9 or 2 of r9c2 having the same color.
+        let expected = cluster
+            .of_color(color)
+            .map(|(index, number)| (index.clone(), number))
+            .collect::<Vec<_>>();
+        assert!(expected.contains(&(BlockIndex::new(Two, Nine), One)));
+        assert!(expected.contains(&(BlockIndex::new(Nine, Two), Two)));
+
+        let steps = MedusaStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].eliminations, expected);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::SudokuNumber,
+    strategies::{SolveStep, Strategy, SudokuSolvingStrategy, combinations},
+};
+
+/// Finds `size` rows (base sets) where a number is only possible in the same `size` columns
+/// (cover sets), so it's removed from those columns in every other row. Same goes for columns.
+pub struct FishStrategy {
+    size: usize,
+}
+
+impl FishStrategy {
+    pub const X_WING: FishStrategy = FishStrategy { size: 2 };
+    pub const SWORDFISH: FishStrategy = FishStrategy { size: 3 };
+    pub const JELLYFISH: FishStrategy = FishStrategy { size: 4 };
+}
+
+impl SudokuSolvingStrategy for FishStrategy {
+    fn strategy(&self) -> Strategy {
+        match self.size {
+            2 => Strategy::XWing,
+            3 => Strategy::Swordfish,
+            _ => Strategy::Jellyfish,
+        }
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for number in SudokuNumber::ALL {
+            for (base, cover) in [
+                (ContainerType::Row, ContainerType::Column),
+                (ContainerType::Column, ContainerType::Row),
+            ] {
+                // Base lines having the number in at most `size` blocks.
+                let lines = SudokuNumber::ALL
+                    .into_iter()
+                    .filter_map(|line| {
+                        let places = places_of(board, base, line, number);
+                        (2..=self.size)
+                            .contains(&places.len())
+                            .then_some((line, places))
+                    })
+                    .collect::<Vec<_>>();
+
+                for fish in combinations(&lines, self.size) {
+                    let mut covers = fish
+                        .iter()
+                        .flat_map(|(_, places)| places.iter().map(|f| f.container_number(cover)))
+                        .collect::<Vec<_>>();
+                    covers.sort();
+                    covers.dedup();
+
+                    if covers.len() != self.size {
+                        continue;
+                    }
+
+                    let bases = fish.iter().map(|(line, _)| *line).collect::<Vec<_>>();
+                    let eliminations = covers
+                        .iter()
+                        .flat_map(|line| places_of(board, cover, *line, number))
+                        .filter(|f| !bases.contains(&f.container_number(base)))
+                        .map(|f| (f, number))
+                        .collect::<Vec<_>>();
+
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_sources(
+                                fish.iter().flat_map(|(_, places)| {
+                                    places.iter().map(|f| (f.clone(), number))
+                                }),
+                            )
+                            .with_sets(
+                                bases.iter().map(|f| (base, *f)),
+                                covers.iter().map(|f| (cover, *f)),
+                            )
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// Blocks of the container that can have the number.
+fn places_of(
+    board: &SudokuBoard,
+    container: ContainerType,
+    container_number: SudokuNumber,
+    number: SudokuNumber,
+) -> Vec<BlockIndex> {
+    board
+        .iter_container(container, container_number)
+        .filter(|f| {
+            f.status
+                .as_possibilities()
+                .is_some_and(|p| p.numbers.has_number(number))
+        })
+        .map(|f| f.index().clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::strategies::test_boards::board_with_number;
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_x_wing() {
+        // 6 in rows 2 and 7 is only possible in columns 3 and 8.
+        let board = board_with_number(Six, |f| {
+            ![Two, Seven].contains(&f.row) || [Three, Eight].contains(&f.col)
+        });
+        assert!(FishStrategy::SWORDFISH.find_steps(&board).is_empty());
+
+        let steps = FishStrategy::X_WING.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+
+        let step = &steps[0];
+        assert_eq!(step.strategy, Strategy::XWing);
+        assert_eq!(
+            step.base_sets,
+            vec![(ContainerType::Row, Two), (ContainerType::Row, Seven)]
+        );
+        assert_eq!(
+            step.cover_sets,
+            vec![
+                (ContainerType::Column, Three),
+                (ContainerType::Column, Eight)
+            ]
+        );
+        assert_eq!(
+            step.source_blocks(),
+            vec![
+                BlockIndex::new(Two, Three),
+                BlockIndex::new(Two, Eight),
+                BlockIndex::new(Seven, Three),
+                BlockIndex::new(Seven, Eight)
+            ]
+        );
+        // Two columns, seven other rows.
+        assert_eq!(step.eliminations.len(), 14);
+        assert!(
+            step.to_string()
+                .starts_with("X-Wing in row 2, row 7 covering column 3, column 8: r2c3(6)")
+        );
+    }
+
+    #[test]
+    fn test_swordfish() {
+        // 6 in columns 1, 4 and 9 is only possible in rows 1, 5 and 8, but not in all of them.
+        let board = board_with_number(Six, |f| {
+            ![One, Four, Nine].contains(&f.col)
+                || ([One, Five, Eight].contains(&f.row) && (f.col, f.row) != (One, Five))
+        });
+        assert!(FishStrategy::X_WING.find_steps(&board).is_empty());
+
+        let steps = FishStrategy::SWORDFISH.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].base_sets,
+            vec![
+                (ContainerType::Column, One),
+                (ContainerType::Column, Four),
+                (ContainerType::Column, Nine)
+            ]
+        );
+        assert_eq!(steps[0].eliminations.len(), 18);
+
+        let mut board = board;
+        board.apply_strategy(&FishStrategy::SWORDFISH.outcome(&board));
+        assert!(FishStrategy::SWORDFISH.outcome(&board).is_empty());
+    }
+
+    #[test]
+    fn test_jellyfish() {
+        // 6 in rows 1, 3, 5 and 7 is only possible in two of columns 2, 4, 6 and 8 each.
+        let board = board_with_number(Six, |f| match f.row {
+            One => [Two, Four].contains(&f.col),
+            Three => [Four, Six].contains(&f.col),
+            Five => [Six, Eight].contains(&f.col),
+            Seven => [Two, Eight].contains(&f.col),
+            _ => true,
+        });
+        assert!(FishStrategy::X_WING.find_steps(&board).is_empty());
+        assert!(FishStrategy::SWORDFISH.find_steps(&board).is_empty());
+
+        let steps = FishStrategy::JELLYFISH.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+
+        let step = &steps[0];
+        assert_eq!(step.strategy, Strategy::Jellyfish);
+        assert_eq!(
+            step.base_sets,
+            [One, Three, Five, Seven].map(|f| (ContainerType::Row, f))
+        );
+        assert_eq!(
+            step.cover_sets,
+            [Two, Four, Six, Eight].map(|f| (ContainerType::Column, f))
+        );
+        // Four columns, five other rows.
+        assert_eq!(step.eliminations.len(), 20);
+        assert!(step.eliminations.iter().all(|(index, number)| {
+            *number == Six
+                && [Two, Four, Six, Eight].contains(&index.col)
+                && ![One, Three, Five, Seven].contains(&index.row)
+        }));
+    }
+}
@@ -0,0 +1,527 @@
+//! Strategies that assume a possible number is true and follow singles from there.
+//!
+//! They're the last resort before guessing, every other strategy should be tried first.
+
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::{SudokuNumber, SudokuNumbers},
+    strategies::{SolveStep, Strategy, SudokuSolvingStrategy, possible_blocks},
+    units,
+};
+
+/// Why an assumption can't be true.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Contradiction {
+    /// A block has no possible number left.
+    NoNumbers(BlockIndex),
+    /// A peer already has the number.
+    Duplicate(BlockIndex, SudokuNumber),
+    /// A container has no place left for the number.
+    NoPlace {
+        container: (ContainerType, SudokuNumber),
+        number: SudokuNumber,
+    },
+}
+
+impl Display for Contradiction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Contradiction::NoNumbers(index) => write!(f, "{} could hold nothing", index),
+            Contradiction::Duplicate(index, number) => {
+                write!(f, "{} is {} as well", index, number.to_u8())
+            }
+            Contradiction::NoPlace {
+                container: (container_type, container_number),
+                number,
+            } => write!(
+                f,
+                "{} {} could hold no {}",
+                container_type,
+                container_number.to_u8(),
+                number.to_u8()
+            ),
+        }
+    }
+}
+
+/// What follows from assuming a possible number is the number of its block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    pub index: BlockIndex,
+    pub number: SudokuNumber,
+    /// Numbers placed because of the assumption in order, with the strategy that placed them.
+    pub implications: Vec<(BlockIndex, SudokuNumber, Strategy)>,
+    /// Set if the assumption leaves the board unsolvable.
+    pub contradiction: Option<Contradiction>,
+}
+
+impl Display for Assumption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "if {} were {}", self.index, self.number.to_u8())?;
+        for (i, (index, number, _)) in self.implications.iter().enumerate() {
+            let separator = if i == 0 { ", then" } else { "," };
+            write!(f, "{} {} is {}", separator, index, number.to_u8())?;
+        }
+
+        if let Some(contradiction) = &self.contradiction {
+            let separator = if self.implications.is_empty() {
+                ", then"
+            } else {
+                ","
+            };
+            write!(f, "{} {}", separator, contradiction)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Follows the assumption that `number` is the number of the block at `index`,
+/// placing naked and hidden singles until nothing is left or the board can't be solved.
+pub fn assume(board: &SudokuBoard, index: &BlockIndex, number: SudokuNumber) -> Assumption {
+    Propagation::new(board).assume(index, number).0
+}
+
+#[derive(Debug, Clone)]
+enum Cell {
+    Placed(SudokuNumber),
+    Possible(SudokuNumbers),
+}
+
+/// A copy of the board's numbers to follow an assumption on.
+#[derive(Clone)]
+struct Propagation {
+    /// By the position of the blocks.
+    cells: Vec<Cell>,
+}
+
+impl Propagation {
+    fn new(board: &SudokuBoard) -> Self {
+        let cells = units::indexes()
+            .iter()
+            .map(|index| {
+                let status = &board.get_block(index).status;
+                match (
+                    status.as_fixed().or(status.as_resolved()),
+                    status.as_possibilities(),
+                ) {
+                    (Some(number), _) => Cell::Placed(*number),
+                    (None, Some(possibilities)) => Cell::Possible(possibilities.numbers),
+                    (None, None) => Cell::Possible(SudokuNumbers::new_all()),
+                }
+            })
+            .collect();
+
+        Self { cells }
+    }
+
+    fn is_possible(&self, index: &BlockIndex, number: SudokuNumber) -> bool {
+        match &self.cells[index.position()] {
+            Cell::Placed(placed) => *placed == number,
+            Cell::Possible(numbers) => numbers.has_number(number),
+        }
+    }
+
+    /// Places the number and removes it from peers, peers left with one number are queued.
+    fn place(
+        &mut self,
+        at: usize,
+        number: SudokuNumber,
+        singles: &mut Vec<usize>,
+    ) -> Result<(), Contradiction> {
+        self.cells[at] = Cell::Placed(number);
+        for peer in units::indexes()[at].peers() {
+            match &mut self.cells[peer.position()] {
+                Cell::Placed(placed) if *placed == number => {
+                    return Err(Contradiction::Duplicate(peer.clone(), number));
+                }
+                Cell::Possible(numbers) if numbers.has_number(number) => {
+                    numbers.del_number(number);
+                    match numbers.count_numbers() {
+                        0 => return Err(Contradiction::NoNumbers(peer.clone())),
+                        1 => singles.push(peer.position()),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn hidden_single(&self) -> Result<Option<(usize, SudokuNumber)>, Contradiction> {
+        for container_type in ContainerType::ALL {
+            for container_number in SudokuNumber::ALL {
+                let blocks = units::container(container_type, container_number);
+                for number in SudokuNumber::ALL {
+                    let mut places =
+                        blocks
+                            .iter()
+                            .map(|f| f.position())
+                            .filter(|f| match &self.cells[*f] {
+                                Cell::Placed(placed) => *placed == number,
+                                Cell::Possible(numbers) => numbers.has_number(number),
+                            });
+
+                    match (places.next(), places.next()) {
+                        (None, _) => {
+                            return Err(Contradiction::NoPlace {
+                                container: (container_type, container_number),
+                                number,
+                            });
+                        }
+                        (Some(at), None) if matches!(self.cells[at], Cell::Possible(_)) => {
+                            return Ok(Some((at, number)));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Places the number and follows singles, leaving the result in `self`.
+    fn assume(mut self, index: &BlockIndex, number: SudokuNumber) -> (Assumption, Self) {
+        let mut assumption = Assumption {
+            index: index.clone(),
+            number,
+            implications: vec![],
+            contradiction: None,
+        };
+
+        // Blocks that are already naked singles come first.
+        let mut singles = (0..self.cells.len())
+            .rev()
+            .filter(|f| matches!(&self.cells[*f], Cell::Possible(numbers) if numbers.count_numbers() == 1))
+            .collect::<Vec<_>>();
+        let mut next = Some((index.position(), number, None));
+        while let Some((at, number, strategy)) = next.take() {
+            if let Some(strategy) = strategy {
+                assumption
+                    .implications
+                    .push((units::indexes()[at].clone(), number, strategy));
+            }
+
+            if let Err(contradiction) = self.place(at, number, &mut singles) {
+                assumption.contradiction = Some(contradiction);
+                break;
+            }
+
+            while let Some(at) = singles.pop() {
+                if let Cell::Possible(numbers) = &self.cells[at] {
+                    next = numbers
+                        .iter()
+                        .next()
+                        .map(|f| (at, f, Some(Strategy::NakedSingle)));
+                    break;
+                }
+            }
+
+            if next.is_none() {
+                match self.hidden_single() {
+                    Ok(single) => {
+                        next = single.map(|(at, f)| (at, f, Some(Strategy::HiddenSingle)));
+                    }
+                    Err(contradiction) => {
+                        assumption.contradiction = Some(contradiction);
+                        break;
+                    }
+                }
+            }
+        }
+
+        (assumption, self)
+    }
+}
+
+/// Follows each possible number once, even if it's assumed more than once.
+struct Assumptions<'b> {
+    board: &'b SudokuBoard,
+    start: Propagation,
+    results: HashMap<(BlockIndex, SudokuNumber), (Assumption, Propagation)>,
+}
+
+impl<'b> Assumptions<'b> {
+    fn new(board: &'b SudokuBoard) -> Self {
+        Self {
+            board,
+            start: Propagation::new(board),
+            results: HashMap::new(),
+        }
+    }
+
+    fn assume(&mut self, index: &BlockIndex, number: SudokuNumber) -> &(Assumption, Propagation) {
+        self.results
+            .entry((index.clone(), number))
+            .or_insert_with(|| self.start.clone().assume(index, number))
+    }
+
+    /// Possible numbers that are removed whichever of the assumptions is true,
+    /// assumptions leading to a contradiction are left out.
+    fn forced(
+        &mut self,
+        branches: &[(BlockIndex, SudokuNumber)],
+    ) -> (Vec<Assumption>, Vec<(BlockIndex, SudokuNumber)>) {
+        for (index, number) in branches {
+            self.assume(index, number.to_owned());
+        }
+
+        let results = branches
+            .iter()
+            .map(|f| &self.results[f])
+            .collect::<Vec<_>>();
+        let assumptions = results.iter().map(|(f, _)| f.clone()).collect();
+
+        let valid = results
+            .iter()
+            .filter(|(f, _)| f.contradiction.is_none())
+            .map(|(_, f)| f)
+            .collect::<Vec<_>>();
+        if valid.is_empty() {
+            return (assumptions, vec![]);
+        }
+
+        let eliminations = possible_blocks(self.board)
+            .into_iter()
+            .flat_map(|(index, numbers)| numbers.iter().map(move |f| (index.clone(), f)))
+            .filter(|(index, number)| valid.iter().all(|f| !f.is_possible(index, *number)))
+            .collect();
+
+        (assumptions, eliminations)
+    }
+}
+
+/// Assumes every possible number of a block in turn, anything removed by all of them is removed.
+pub struct CellForcingChainStrategy;
+
+impl SudokuSolvingStrategy for CellForcingChainStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::CellForcingChain
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut assumptions = Assumptions::new(board);
+        let mut steps = vec![];
+
+        for (index, numbers) in possible_blocks(board)
+            .into_iter()
+            .filter(|(_, f)| (2..=3).contains(&f.count_numbers()))
+        {
+            let branches = numbers
+                .iter()
+                .map(|f| (index.clone(), f))
+                .collect::<Vec<_>>();
+            let (forced, eliminations) = assumptions.forced(&branches);
+            if eliminations.is_empty() {
+                continue;
+            }
+
+            steps.push(
+                SolveStep::new(self.strategy())
+                    .with_sources(branches)
+                    .with_assumptions(forced)
+                    .with_eliminations(eliminations),
+            );
+        }
+
+        steps
+    }
+}
+
+/// Assumes every place of a number in a container in turn, anything removed by all of them
+/// is removed.
+pub struct UnitForcingChainStrategy;
+
+impl SudokuSolvingStrategy for UnitForcingChainStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::UnitForcingChain
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut assumptions = Assumptions::new(board);
+        let mut steps = vec![];
+
+        for container_type in ContainerType::ALL {
+            for container_number in SudokuNumber::ALL {
+                for number in SudokuNumber::ALL {
+                    let branches = board
+                        .iter_container(container_type, container_number)
+                        .filter(|f| {
+                            f.status
+                                .as_possibilities()
+                                .is_some_and(|p| p.numbers.has_number(number))
+                        })
+                        .map(|f| (f.index().clone(), number))
+                        .collect::<Vec<_>>();
+                    if !(2..=3).contains(&branches.len()) {
+                        continue;
+                    }
+
+                    let (forced, eliminations) = assumptions.forced(&branches);
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_container(container_type, container_number)
+                            .with_sources(branches)
+                            .with_assumptions(forced)
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// Assumes a possible number and removes it if that leads to a contradiction.
+pub struct NishioStrategy;
+
+impl SudokuSolvingStrategy for NishioStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::Nishio
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let start = Propagation::new(board);
+        let mut steps = vec![];
+
+        for (index, numbers) in possible_blocks(board) {
+            for number in numbers.iter() {
+                let (assumption, _) = start.clone().assume(index, number);
+                if assumption.contradiction.is_none() {
+                    continue;
+                }
+
+                steps.push(
+                    SolveStep::new(self.strategy())
+                        .with_sources([(index.clone(), number)])
+                        .with_assumptions([assumption])
+                        .with_eliminations([(index.clone(), number)]),
+                );
+            }
+        }
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Possibilities, SudokuBlockStatus};
+
+    use super::*;
+
+    /// The first sample, where every empty block can only be its number in the solution.
+    fn solved_sample() -> SudokuBoard {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        let solution = board.solution().unwrap();
+        for block in board.get_blocks_mut().filter(|f| !f.is_fixed()) {
+            let number = solution.get_block(block.index()).status.as_resolved();
+            block.status = SudokuBlockStatus::Possibilities(Possibilities::new(
+                SudokuNumbers::new(number.copied()),
+            ));
+        }
+        board
+    }
+
+    /// The solved sample, where a block can also be the number of an empty peer.
+    fn sample_with_wrong_number() -> (SudokuBoard, BlockIndex, BlockIndex, SudokuNumber) {
+        let mut board = solved_sample();
+        let (index, peer, wrong) = possible_blocks(&board)
+            .into_iter()
+            .find_map(|(index, _)| {
+                possible_blocks(&board)
+                    .into_iter()
+                    .find(|(f, _)| f.is_peer_of(index))
+                    .map(|(peer, numbers)| {
+                        (index.clone(), peer.clone(), numbers.iter().next().unwrap())
+                    })
+            })
+            .unwrap();
+
+        board
+            .get_block_mut(&index)
+            .status
+            .as_possibilities_mut()
+            .unwrap()
+            .numbers
+            .set_number(wrong);
+        (board, index, peer, wrong)
+    }
+
+    #[test]
+    fn test_assume() {
+        let board = solved_sample();
+        let blocks = possible_blocks(&board);
+        let (index, numbers) = blocks[0];
+
+        // Every other block is a naked single.
+        let assumption = assume(&board, index, numbers.iter().next().unwrap());
+        assert_eq!(assumption.contradiction, None);
+        assert_eq!(assumption.implications.len(), blocks.len() - 1);
+        assert!(
+            assumption
+                .implications
+                .iter()
+                .all(|(_, _, f)| *f == Strategy::NakedSingle)
+        );
+    }
+
+    #[test]
+    fn test_nishio() {
+        let (board, index, peer, wrong) = sample_with_wrong_number();
+
+        let steps = NishioStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].eliminations, vec![(index.clone(), wrong)]);
+
+        let assumption = &steps[0].assumptions[0];
+        assert_eq!(
+            assumption.contradiction,
+            Some(Contradiction::NoNumbers(peer.clone()))
+        );
+        assert_eq!(
+            assumption.to_string(),
+            format!(
+                "if {} were {}, then {} could hold nothing",
+                index,
+                wrong.to_u8(),
+                peer
+            )
+        );
+    }
+
+    #[test]
+    fn test_cell_forcing_chain() {
+        let (board, index, _, wrong) = sample_with_wrong_number();
+
+        let steps = CellForcingChainStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].source_blocks(), vec![index.clone()]);
+        assert_eq!(steps[0].assumptions.len(), 2);
+        assert_eq!(steps[0].eliminations, vec![(index, wrong)]);
+    }
+
+    #[test]
+    fn test_unit_forcing_chain() {
+        let (board, index, peer, wrong) = sample_with_wrong_number();
+
+        let steps = UnitForcingChainStrategy.find_steps(&board);
+        assert!(!steps.is_empty());
+        assert!(
+            steps
+                .iter()
+                .all(|f| f.source_blocks() == vec![index.clone(), peer.clone()]
+                    && f.eliminations == vec![(index.clone(), wrong)])
+        );
+    }
+}
@@ -1,107 +1,51 @@
 use crate::{
-    BlockIndex, SudokuBlock, SudokuBoard,
+    BlockIndex, ContainerType, SudokuBlock, SudokuBoard,
     numbers::{SudokuNumber, SudokuNumbers},
-    strategies::SudokuSolvingStrategy,
+    strategies::{SolveStep, SudokuSolvingStrategy, peers_having},
 };
 
 pub struct HiddenSingleStrategy;
 
 impl SudokuSolvingStrategy for HiddenSingleStrategy {
-    const STRATEGY: super::Strategy = super::Strategy::HiddenSingle;
-
-    fn update_possible_numbers(&self, board: &mut crate::SudokuBoard, show_only_effect: bool) {
-        for (row, col) in SudokuNumber::iter_numbers() {
-            let mut hidden_number = None;
-
-            if let Some(row_hidden) = get_hidden_single(board, row, col, |b| b.get_row(row)) {
-                hidden_number = Some(row_hidden);
-            } else if let Some(col_hidden) = get_hidden_single(board, row, col, |b| b.get_col(col))
-            {
-                hidden_number = Some(col_hidden);
-            } else if let Some(square_hidden) = get_hidden_single(board, row, col, |b| {
-                b.get_square(SudokuBoard::square_number(row, col))
-            }) {
-                hidden_number = Some(square_hidden);
-            }
-
-            if let Some(hidden) = hidden_number {
-                if let Some(possibilities) = board
-                    .get_block_mut(&BlockIndex::new(row, col))
-                    .status
-                    .as_possibilities_mut()
-                {
-                    if !show_only_effect {
-                        *possibilities = Default::default();
-                        possibilities.numbers.set_number(hidden);
-                    } else {
-                        possibilities.update_strategy_marker(
-                            hidden,
-                            super::StrategyMarker {
-                                strategy: super::Strategy::HiddenSingle,
-                                effect: super::StrategyEffect::Source,
-                            },
-                        );
-                    }
-                }
-
-                for possibilities in board
-                    .get_row_mut(row)
-                    .filter(|b| b.col() != col)
-                    .filter_map(|f| f.status.as_possibilities_mut())
-                {
-                    if !show_only_effect {
-                        possibilities.numbers.del_number(hidden);
-                        possibilities.clear_strategy_marker(hidden);
-                    } else {
-                        possibilities.update_strategy_marker(
-                            hidden,
-                            super::StrategyMarker {
-                                strategy: super::Strategy::HiddenSingle,
-                                effect: super::StrategyEffect::Effected,
-                            },
-                        );
-                    }
-                }
+    fn strategy(&self) -> super::Strategy {
+        super::Strategy::HiddenSingle
+    }
 
-                for possibilities in board
-                    .get_col_mut(col)
-                    .filter(|b| b.row() != row)
-                    .filter_map(|f| f.status.as_possibilities_mut())
-                {
-                    if !show_only_effect {
-                        possibilities.numbers.del_number(hidden);
-                        possibilities.clear_strategy_marker(hidden);
-                    } else {
-                        possibilities.update_strategy_marker(
-                            hidden,
-                            super::StrategyMarker {
-                                strategy: super::Strategy::HiddenSingle,
-                                effect: super::StrategyEffect::Effected,
-                            },
-                        );
-                    }
-                }
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
 
-                for possibilities in board
-                    .get_square_mut(SudokuBoard::square_number(row, col))
-                    .filter(|b| b.col() != col && b.row() != row)
-                    .filter_map(|f| f.status.as_possibilities_mut())
-                {
-                    if !show_only_effect {
-                        possibilities.numbers.del_number(hidden);
-                        possibilities.clear_strategy_marker(hidden);
-                    } else {
-                        possibilities.update_strategy_marker(
-                            hidden,
-                            super::StrategyMarker {
-                                strategy: super::Strategy::HiddenSingle,
-                                effect: super::StrategyEffect::Effected,
-                            },
-                        );
-                    }
-                }
+        for (row, col) in SudokuNumber::iter_numbers() {
+            let square = SudokuBoard::square_number(row, col);
+            let hidden = get_hidden_single(board, row, col, |b| {
+                b.iter_container(ContainerType::Row, row)
+            })
+            .map(|f| (ContainerType::Row, row, f))
+            .or_else(|| {
+                get_hidden_single(board, row, col, |b| {
+                    b.iter_container(ContainerType::Column, col)
+                })
+                .map(|f| (ContainerType::Column, col, f))
+            })
+            .or_else(|| {
+                get_hidden_single(board, row, col, |b| {
+                    b.iter_container(ContainerType::Square, square)
+                })
+                .map(|f| (ContainerType::Square, square, f))
+            });
+
+            if let Some((container, container_number, hidden)) = hidden {
+                let index = BlockIndex::new(row, col);
+                steps.push(
+                    SolveStep::new(self.strategy())
+                        .with_container(container, container_number)
+                        .with_sources([(index.clone(), hidden)])
+                        .with_placement(index.clone(), hidden)
+                        .with_eliminations(peers_having(board, &index, hidden)),
+                );
             }
         }
+
+        steps
     }
 }
 
@@ -121,30 +65,15 @@ where
     let row_pos =
         get_all_possible_numbers(container(board).filter(|x| !(x.index() == block.index())));
 
-    let hidden = possibles
-        .numbers
-        .iter()
-        .filter(|f| !row_pos.has_number(*f))
-        .collect::<Vec<_>>();
-    if hidden.len() == 1 {
-        Some(hidden[0])
-    } else {
-        None
-    }
+    possibles.numbers.difference(&row_pos).single()
 }
 
 pub fn get_all_possible_numbers<'s>(
     iterator: impl Iterator<Item = &'s SudokuBlock>,
 ) -> SudokuNumbers {
-    iterator.filter_map(|f| f.status.as_possibilities()).fold(
-        SudokuNumbers::default(),
-        |mut acc, fold| {
-            for f in fold.numbers.iter() {
-                acc.set_number(f);
-            }
-            acc
-        },
-    )
+    iterator
+        .filter_map(|f| f.status.as_possibilities())
+        .fold(SudokuNumbers::default(), |acc, f| acc | f.numbers)
 }
 
 #[cfg(test)]
@@ -0,0 +1,201 @@
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::{SudokuNumber, SudokuNumbers},
+    strategies::{SolveStep, Strategy, SudokuSolvingStrategy, combinations},
+};
+
+/// Finds `size` numbers of a container that are only possible in `size` blocks,
+/// so every other possible number can be removed from those blocks.
+pub struct HiddenSubsetStrategy {
+    size: usize,
+}
+
+impl HiddenSubsetStrategy {
+    pub const PAIR: HiddenSubsetStrategy = HiddenSubsetStrategy { size: 2 };
+    pub const TRIPLE: HiddenSubsetStrategy = HiddenSubsetStrategy { size: 3 };
+    pub const QUAD: HiddenSubsetStrategy = HiddenSubsetStrategy { size: 4 };
+}
+
+impl SudokuSolvingStrategy for HiddenSubsetStrategy {
+    fn strategy(&self) -> Strategy {
+        match self.size {
+            2 => Strategy::HiddenPair,
+            3 => Strategy::HiddenTriple,
+            _ => Strategy::HiddenQuad,
+        }
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for container in ContainerType::ALL {
+            for container_number in SudokuNumber::ALL {
+                let blocks = board
+                    .iter_container(container, container_number)
+                    .filter_map(|f| f.status.as_possibilities().map(|p| (f.index(), &p.numbers)))
+                    .collect::<Vec<_>>();
+
+                // Numbers that are possible in at most `size` blocks of the container,
+                // skipping placed ones that are still marked somewhere.
+                let placed =
+                    SudokuBoard::get_numbers(board.iter_container(container, container_number));
+                let candidates = placed
+                    .get_missing_numbers()
+                    .filter_map(|number| {
+                        let places = blocks
+                            .iter()
+                            .filter(|(_, numbers)| numbers.has_number(number))
+                            .map(|(index, _)| *index)
+                            .collect::<Vec<_>>();
+                        (1..=self.size)
+                            .contains(&places.len())
+                            .then_some((number, places))
+                    })
+                    .collect::<Vec<_>>();
+
+                for subset in combinations(&candidates, self.size) {
+                    let mut places: Vec<&BlockIndex> = vec![];
+                    for index in subset.iter().flat_map(|(_, places)| places) {
+                        if !places.contains(index) {
+                            places.push(index);
+                        }
+                    }
+
+                    if places.len() != self.size {
+                        continue;
+                    }
+
+                    let numbers = SudokuNumbers::new(subset.iter().map(|(number, _)| *number));
+                    let eliminations = blocks
+                        .iter()
+                        .filter(|(index, _)| places.contains(index))
+                        .flat_map(|(index, possibles)| {
+                            possibles
+                                .difference(&numbers)
+                                .iter()
+                                .map(|f| ((*index).clone(), f))
+                        })
+                        .collect::<Vec<_>>();
+
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_container(container, container_number)
+                            .with_sources(
+                                blocks
+                                    .iter()
+                                    .filter(|(index, _)| places.contains(index))
+                                    .flat_map(|(index, possibles)| {
+                                        numbers
+                                            .intersection(possibles)
+                                            .iter()
+                                            .map(|f| ((*index).clone(), f))
+                                    }),
+                            )
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        SudokuBlockStatus,
+        strategies::{StrategyEffect, test_boards::board_with},
+    };
+
+    use super::*;
+    use SudokuNumber::*;
+
+    /// First row has (1, 2, 3) only in its first three blocks, other rows have every number.
+    fn hidden_triple(index: &BlockIndex) -> SudokuNumbers {
+        match (index.row, index.col) {
+            (One, One) => SudokuNumbers::new([One, Two, Five, Six]),
+            (One, Two) => SudokuNumbers::new([Two, Three, Seven]),
+            (One, Three) => SudokuNumbers::new([One, Three, Eight, Nine]),
+            (One, _) => SudokuNumbers::new([Four, Five, Six, Seven, Eight, Nine]),
+            _ => SudokuNumbers::new_all(),
+        }
+    }
+
+    #[test]
+    fn test_find_hidden_triple() {
+        let board = board_with(hidden_triple);
+        assert!(HiddenSubsetStrategy::PAIR.find_steps(&board).is_empty());
+
+        let steps = HiddenSubsetStrategy::TRIPLE.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+
+        let step = &steps[0];
+        assert_eq!(step.strategy, Strategy::HiddenTriple);
+        assert_eq!(step.container, Some((ContainerType::Row, One)));
+        assert_eq!(
+            step.source_blocks(),
+            vec![
+                BlockIndex::new(One, One),
+                BlockIndex::new(One, Two),
+                BlockIndex::new(One, Three)
+            ]
+        );
+        assert_eq!(
+            step.eliminations,
+            vec![
+                (BlockIndex::new(One, One), Five),
+                (BlockIndex::new(One, One), Six),
+                (BlockIndex::new(One, Two), Seven),
+                (BlockIndex::new(One, Three), Eight),
+                (BlockIndex::new(One, Three), Nine),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skips_placed_numbers() {
+        // 4 is placed in r1c9 but still marked in r1c1 and r1c2, along with 5.
+        let mut board = board_with(|f| match (f.row, f.col) {
+            (One, One) => SudokuNumbers::new([Four, Five, Six]),
+            (One, Two) => SudokuNumbers::new([Four, Five, Seven]),
+            (One, _) => SudokuNumbers::new([One, Two, Three, Six, Seven, Eight, Nine]),
+            _ => SudokuNumbers::new_all(),
+        });
+        board.get_block_mut(&BlockIndex::new(One, Nine)).status = SudokuBlockStatus::Resolved(Four);
+
+        assert!(HiddenSubsetStrategy::PAIR.find_steps(&board).is_empty());
+    }
+
+    #[test]
+    fn test_preview_and_apply() {
+        let mut board = board_with(hidden_triple);
+        let outcome = HiddenSubsetStrategy::TRIPLE.outcome(&board);
+
+        board.preview_strategy(&outcome);
+
+        let block = board.get_block(&BlockIndex::new(One, One));
+        let block = block.status.as_possibilities().unwrap();
+        assert!(block.has_strategy_effect(&One).unwrap().is_source());
+        assert_eq!(
+            block.has_strategy_effect(&Five).unwrap().effect(),
+            &StrategyEffect::Effected {
+                index: BlockIndex::new(One, One),
+                number: Some(Five)
+            }
+        );
+
+        board.apply_strategy(&outcome);
+
+        let block = board.get_block(&BlockIndex::new(One, Two));
+        assert_eq!(
+            block.status.as_possibilities().unwrap().numbers,
+            SudokuNumbers::new([Two, Three])
+        );
+        assert!(HiddenSubsetStrategy::TRIPLE.outcome(&board).is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{
+    ContainerType, SudokuBoard,
+    numbers::SudokuNumber,
+    strategies::{SolveStep, Strategy, SudokuSolvingStrategy},
+};
+
+/// Finds numbers locked inside the intersection of a square and a line.
+///
+/// - Pointing: a number of a square is only possible on one row (or column), so it's
+///   removed from the rest of that line.
+/// - Box/line reduction: a number of a row (or column) is only possible inside one square,
+///   so it's removed from the rest of that square.
+pub struct LockedCandidatesStrategy;
+
+impl SudokuSolvingStrategy for LockedCandidatesStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::LockedCandidates
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for container in ContainerType::ALL {
+            for container_number in SudokuNumber::ALL {
+                for number in SudokuNumber::ALL {
+                    let places = board
+                        .iter_container(container, container_number)
+                        .filter(|f| {
+                            f.status
+                                .as_possibilities()
+                                .is_some_and(|p| p.numbers.has_number(number))
+                        })
+                        .map(|f| f.index())
+                        .collect::<Vec<_>>();
+
+                    if places.len() < 2 {
+                        continue;
+                    }
+
+                    // The other container every place shares.
+                    let (target, target_number) = match container {
+                        ContainerType::Square => {
+                            if places.iter().all(|f| f.row == places[0].row) {
+                                (ContainerType::Row, places[0].row)
+                            } else if places.iter().all(|f| f.col == places[0].col) {
+                                (ContainerType::Column, places[0].col)
+                            } else {
+                                continue;
+                            }
+                        }
+                        ContainerType::Row | ContainerType::Column => {
+                            let square = places[0].square_number();
+                            if places.iter().all(|f| f.square_number() == square) {
+                                (ContainerType::Square, square)
+                            } else {
+                                continue;
+                            }
+                        }
+                    };
+
+                    let eliminations = board
+                        .iter_container(target, target_number)
+                        .filter(|f| !places.contains(&f.index()))
+                        .filter(|f| {
+                            f.status
+                                .as_possibilities()
+                                .is_some_and(|p| p.numbers.has_number(number))
+                        })
+                        .map(|f| (f.index().clone(), number))
+                        .collect::<Vec<_>>();
+
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_container(container, container_number)
+                            .with_sources(places.iter().map(|f| ((*f).clone(), number)))
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlockIndex, strategies::test_boards::board_with_number};
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_pointing() {
+        // 4 in the first square is only possible on its first row.
+        let board = board_with_number(Four, |f| f.square_number() != One || f.row == One);
+        let steps = LockedCandidatesStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+
+        let step = &steps[0];
+        assert_eq!(step.container, Some((ContainerType::Square, One)));
+        assert_eq!(
+            step.source_blocks(),
+            vec![
+                BlockIndex::new(One, One),
+                BlockIndex::new(One, Two),
+                BlockIndex::new(One, Three)
+            ]
+        );
+        assert_eq!(step.eliminations.len(), 6);
+        assert!(
+            step.eliminations
+                .iter()
+                .all(|(index, number)| index.row == One && index.col > Three && *number == Four)
+        );
+    }
+
+    #[test]
+    fn test_box_line_reduction() {
+        // 4 in the first column is only possible inside the first square.
+        let board = board_with_number(Four, |f| f.col != One || f.row <= Three);
+        let steps = LockedCandidatesStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+
+        let step = &steps[0];
+        assert_eq!(step.container, Some((ContainerType::Column, One)));
+        assert_eq!(step.eliminations.len(), 6);
+        assert!(step.eliminations.iter().all(|(index, number)| {
+            index.square_number() == One && index.col != One && *number == Four
+        }));
+
+        let mut board = board;
+        board.apply_strategy(&LockedCandidatesStrategy.outcome(&board));
+        assert!(LockedCandidatesStrategy.outcome(&board).is_empty());
+    }
+}
@@ -1,16 +1,60 @@
 use std::fmt::{Display, Write as _};
 
-use crate::SudokuBoard;
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    difficulty::SudokuBoardDifficulty,
+    numbers::{SudokuNumber, SudokuNumbers},
+    strategies::{chains::Chain, coloring::CandidateColor, forcing::Assumption},
+};
 
+pub mod als;
+pub mod chains;
+pub mod coloring;
+pub mod fish;
+pub mod forcing;
 pub mod hidden_single;
+pub mod hidden_subset;
+pub mod locked_candidates;
 pub mod naked_pair;
 pub mod naked_single;
+pub mod naked_subset;
+pub mod pipeline;
+pub mod single_digit;
+pub mod uniqueness;
+pub mod wings;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Strategy {
     NakedSingle,
     HiddenSingle,
+    LockedCandidates,
     NakedPair,
+    HiddenPair,
+    NakedTriple,
+    HiddenTriple,
+    XWing,
+    Skyscraper,
+    TwoStringKite,
+    SimpleColoring,
+    XYWing,
+    UniqueRectangle,
+    BivalueUniversalGrave,
+    EmptyRectangle,
+    NakedQuad,
+    HiddenQuad,
+    Swordfish,
+    XYZWing,
+    WWing,
+    Medusa,
+    Jellyfish,
+    XChain,
+    XYChain,
+    AlsXz,
+    AlternatingInferenceChain,
+    DeathBlossom,
+    CellForcingChain,
+    UnitForcingChain,
+    Nishio,
 }
 
 impl Display for Strategy {
@@ -18,7 +62,177 @@ impl Display for Strategy {
         match self {
             Strategy::HiddenSingle => f.write_char('H'),
             Strategy::NakedSingle => f.write_char('S'),
+            Strategy::LockedCandidates => f.write_char('L'),
             Strategy::NakedPair => f.write_char('P'),
+            Strategy::HiddenPair => f.write_str("HP"),
+            Strategy::NakedTriple => f.write_str("NT"),
+            Strategy::NakedQuad => f.write_str("NQ"),
+            Strategy::XWing => f.write_char('X'),
+            Strategy::Swordfish => f.write_str("SF"),
+            Strategy::Jellyfish => f.write_str("JF"),
+            Strategy::XYWing => f.write_str("XY"),
+            Strategy::XYZWing => f.write_str("XYZ"),
+            Strategy::WWing => f.write_char('W'),
+            Strategy::Skyscraper => f.write_str("SK"),
+            Strategy::TwoStringKite => f.write_str("2K"),
+            Strategy::EmptyRectangle => f.write_str("ER"),
+            Strategy::HiddenTriple => f.write_str("HT"),
+            Strategy::HiddenQuad => f.write_str("HQ"),
+            Strategy::SimpleColoring => f.write_str("SC"),
+            Strategy::Medusa => f.write_str("3D"),
+            Strategy::XChain => f.write_str("XC"),
+            Strategy::XYChain => f.write_str("XYC"),
+            Strategy::AlternatingInferenceChain => f.write_str("AIC"),
+            Strategy::UniqueRectangle => f.write_str("UR"),
+            Strategy::BivalueUniversalGrave => f.write_str("BUG"),
+            Strategy::AlsXz => f.write_str("ALS"),
+            Strategy::DeathBlossom => f.write_str("DB"),
+            Strategy::CellForcingChain => f.write_str("CFC"),
+            Strategy::UnitForcingChain => f.write_str("UFC"),
+            Strategy::Nishio => f.write_str("NI"),
+        }
+    }
+}
+
+impl Strategy {
+    /// Every strategy, from the simplest to the hardest.
+    pub const ALL: [Strategy; 30] = [
+        Strategy::NakedSingle,
+        Strategy::HiddenSingle,
+        Strategy::LockedCandidates,
+        Strategy::NakedPair,
+        Strategy::HiddenPair,
+        Strategy::NakedTriple,
+        Strategy::HiddenTriple,
+        Strategy::XWing,
+        Strategy::Skyscraper,
+        Strategy::TwoStringKite,
+        Strategy::SimpleColoring,
+        Strategy::XYWing,
+        Strategy::UniqueRectangle,
+        Strategy::BivalueUniversalGrave,
+        Strategy::EmptyRectangle,
+        Strategy::NakedQuad,
+        Strategy::HiddenQuad,
+        Strategy::Swordfish,
+        Strategy::XYZWing,
+        Strategy::WWing,
+        Strategy::Medusa,
+        Strategy::Jellyfish,
+        Strategy::XChain,
+        Strategy::XYChain,
+        Strategy::AlsXz,
+        Strategy::AlternatingInferenceChain,
+        Strategy::DeathBlossom,
+        Strategy::CellForcingChain,
+        Strategy::UnitForcingChain,
+        Strategy::Nishio,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::NakedSingle => "Naked single",
+            Strategy::HiddenSingle => "Hidden single",
+            Strategy::LockedCandidates => "Locked candidates",
+            Strategy::NakedPair => "Naked pair",
+            Strategy::HiddenPair => "Hidden pair",
+            Strategy::NakedTriple => "Naked triple",
+            Strategy::NakedQuad => "Naked quad",
+            Strategy::XWing => "X-Wing",
+            Strategy::Swordfish => "Swordfish",
+            Strategy::Jellyfish => "Jellyfish",
+            Strategy::XYWing => "XY-Wing",
+            Strategy::XYZWing => "XYZ-Wing",
+            Strategy::WWing => "W-Wing",
+            Strategy::Skyscraper => "Skyscraper",
+            Strategy::TwoStringKite => "2-String Kite",
+            Strategy::EmptyRectangle => "Empty rectangle",
+            Strategy::HiddenTriple => "Hidden triple",
+            Strategy::HiddenQuad => "Hidden quad",
+            Strategy::SimpleColoring => "Simple coloring",
+            Strategy::Medusa => "3D Medusa",
+            Strategy::XChain => "X-Chain",
+            Strategy::XYChain => "XY-Chain",
+            Strategy::AlternatingInferenceChain => "Alternating inference chain",
+            Strategy::UniqueRectangle => "Unique rectangle",
+            Strategy::BivalueUniversalGrave => "BUG+1",
+            Strategy::AlsXz => "ALS-XZ",
+            Strategy::DeathBlossom => "Death blossom",
+            Strategy::CellForcingChain => "Cell forcing chain",
+            Strategy::UnitForcingChain => "Unit forcing chain",
+            Strategy::Nishio => "Nishio",
+        }
+    }
+
+    /// The least difficulty of a board that needs this strategy to be solved.
+    pub fn difficulty(&self) -> SudokuBoardDifficulty {
+        match self {
+            Strategy::NakedSingle => SudokuBoardDifficulty::Easy,
+            Strategy::HiddenSingle => SudokuBoardDifficulty::Normal,
+            Strategy::LockedCandidates => SudokuBoardDifficulty::Normal,
+            Strategy::NakedPair => SudokuBoardDifficulty::Hard,
+            Strategy::HiddenPair => SudokuBoardDifficulty::Hard,
+            Strategy::NakedTriple => SudokuBoardDifficulty::Hard,
+            Strategy::NakedQuad => SudokuBoardDifficulty::Expert,
+            Strategy::XWing => SudokuBoardDifficulty::Hard,
+            Strategy::Swordfish => SudokuBoardDifficulty::Expert,
+            Strategy::Jellyfish => SudokuBoardDifficulty::Expert,
+            Strategy::XYWing => SudokuBoardDifficulty::Hard,
+            Strategy::XYZWing => SudokuBoardDifficulty::Expert,
+            Strategy::WWing => SudokuBoardDifficulty::Expert,
+            Strategy::Skyscraper => SudokuBoardDifficulty::Hard,
+            Strategy::TwoStringKite => SudokuBoardDifficulty::Hard,
+            Strategy::EmptyRectangle => SudokuBoardDifficulty::Expert,
+            Strategy::HiddenTriple => SudokuBoardDifficulty::Hard,
+            Strategy::HiddenQuad => SudokuBoardDifficulty::Expert,
+            Strategy::SimpleColoring => SudokuBoardDifficulty::Hard,
+            Strategy::Medusa => SudokuBoardDifficulty::Expert,
+            Strategy::XChain => SudokuBoardDifficulty::Expert,
+            Strategy::XYChain => SudokuBoardDifficulty::Expert,
+            Strategy::AlternatingInferenceChain => SudokuBoardDifficulty::Expert,
+            Strategy::UniqueRectangle => SudokuBoardDifficulty::Hard,
+            Strategy::BivalueUniversalGrave => SudokuBoardDifficulty::Hard,
+            Strategy::AlsXz => SudokuBoardDifficulty::Expert,
+            Strategy::DeathBlossom => SudokuBoardDifficulty::Expert,
+            Strategy::CellForcingChain => SudokuBoardDifficulty::Expert,
+            Strategy::UnitForcingChain => SudokuBoardDifficulty::Expert,
+            Strategy::Nishio => SudokuBoardDifficulty::Expert,
+        }
+    }
+
+    /// A weight showing how hard is it to apply this strategy each time.
+    pub fn score(&self) -> u32 {
+        match self {
+            Strategy::NakedSingle => 1,
+            Strategy::HiddenSingle => 2,
+            Strategy::LockedCandidates => 5,
+            Strategy::NakedPair => 10,
+            Strategy::HiddenPair => 15,
+            Strategy::NakedTriple => 18,
+            Strategy::NakedQuad => 25,
+            Strategy::XWing => 20,
+            Strategy::Swordfish => 30,
+            Strategy::Jellyfish => 40,
+            Strategy::XYWing => 25,
+            Strategy::XYZWing => 30,
+            Strategy::WWing => 30,
+            Strategy::Skyscraper => 22,
+            Strategy::TwoStringKite => 22,
+            Strategy::EmptyRectangle => 28,
+            Strategy::HiddenTriple => 20,
+            Strategy::HiddenQuad => 30,
+            Strategy::SimpleColoring => 25,
+            Strategy::Medusa => 35,
+            Strategy::XChain => 40,
+            Strategy::XYChain => 45,
+            Strategy::AlternatingInferenceChain => 50,
+            Strategy::UniqueRectangle => 25,
+            Strategy::BivalueUniversalGrave => 22,
+            Strategy::AlsXz => 45,
+            Strategy::DeathBlossom => 55,
+            Strategy::CellForcingChain => 60,
+            Strategy::UnitForcingChain => 60,
+            Strategy::Nishio => 70,
         }
     }
 }
@@ -27,9 +241,23 @@ impl Display for Strategy {
 pub struct StrategyMarker {
     strategy: Strategy,
     effect: StrategyEffect,
+    color: Option<CandidateColor>,
 }
 
 impl StrategyMarker {
+    pub fn new(strategy: Strategy, effect: StrategyEffect) -> Self {
+        Self {
+            strategy,
+            effect,
+            color: None,
+        }
+    }
+
+    pub fn with_color(mut self, color: Option<CandidateColor>) -> Self {
+        self.color = color;
+        self
+    }
+
     pub fn is_effected(&self) -> bool {
         self.effect.is_effected()
     }
@@ -41,6 +269,15 @@ impl StrategyMarker {
     pub fn strategy(&self) -> Strategy {
         self.strategy
     }
+
+    pub fn effect(&self) -> &StrategyEffect {
+        &self.effect
+    }
+
+    /// The color of a source painted by a coloring strategy.
+    pub fn color(&self) -> Option<CandidateColor> {
+        self.color
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -48,14 +285,13 @@ pub enum StrategyEffect {
     /// The source.
     Source,
 
-    /// Effected by a [`StrategyMarker::Source`].
-    Effected,
-    // {
-    //     /// The index of source block.
-    //     index: BlockIndex,
-    //     /// The source number (mostly in possibilities).
-    //     number: Option<SudokuNumber>,
-    // },
+    /// Effected by a [`StrategyEffect::Source`].
+    Effected {
+        /// The index of source block.
+        index: BlockIndex,
+        /// The source number (mostly in possibilities).
+        number: Option<SudokuNumber>,
+    },
 }
 
 impl StrategyEffect {
@@ -72,12 +308,522 @@ impl StrategyEffect {
     /// [`Effected`]: StrategyEffect::Effected
     #[must_use]
     pub fn is_effected(&self) -> bool {
-        matches!(self, Self::Effected)
+        matches!(self, Self::Effected { .. })
+    }
+}
+
+/// A single deduction found by a strategy, with everything needed to explain why it's valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveStep {
+    pub strategy: Strategy,
+    /// The row, column or square the pattern was found in (if it's bound to one).
+    pub container: Option<(ContainerType, SudokuNumber)>,
+    /// Possible numbers (and their blocks) forming the pattern.
+    pub sources: Vec<(BlockIndex, SudokuNumber)>,
+    /// Containers holding the pattern, for strategies spanning more than one (like fish).
+    pub base_sets: Vec<(ContainerType, SudokuNumber)>,
+    /// Containers the eliminations are made in, when they differ from [`SolveStep::base_sets`].
+    pub cover_sets: Vec<(ContainerType, SudokuNumber)>,
+    /// A number that can be placed in a block as a result of this step.
+    pub placement: Option<(BlockIndex, SudokuNumber)>,
+    /// Possible numbers that should be removed as a result of this step.
+    pub eliminations: Vec<(BlockIndex, SudokuNumber)>,
+    /// Colors of the sources, for coloring strategies.
+    pub coloring: Vec<(BlockIndex, SudokuNumber, CandidateColor)>,
+    /// The sources in the order they're linked, for chain strategies.
+    pub chain: Option<Chain>,
+    /// What follows from assuming each source, for forcing strategies.
+    pub assumptions: Vec<Assumption>,
+}
+
+impl SolveStep {
+    pub fn new(strategy: Strategy) -> Self {
+        Self {
+            strategy,
+            container: None,
+            sources: Default::default(),
+            base_sets: Default::default(),
+            cover_sets: Default::default(),
+            placement: None,
+            eliminations: Default::default(),
+            coloring: Default::default(),
+            chain: None,
+            assumptions: Default::default(),
+        }
+    }
+
+    pub fn with_container(mut self, container_type: ContainerType, number: SudokuNumber) -> Self {
+        self.container = Some((container_type, number));
+        self
+    }
+
+    pub fn with_sources(
+        mut self,
+        sources: impl IntoIterator<Item = (BlockIndex, SudokuNumber)>,
+    ) -> Self {
+        self.sources.extend(sources);
+        self
+    }
+
+    pub fn with_sets(
+        mut self,
+        base_sets: impl IntoIterator<Item = (ContainerType, SudokuNumber)>,
+        cover_sets: impl IntoIterator<Item = (ContainerType, SudokuNumber)>,
+    ) -> Self {
+        self.base_sets.extend(base_sets);
+        self.cover_sets.extend(cover_sets);
+        self
+    }
+
+    pub fn with_placement(mut self, index: BlockIndex, number: SudokuNumber) -> Self {
+        self.placement = Some((index, number));
+        self
+    }
+
+    pub fn with_eliminations(
+        mut self,
+        eliminations: impl IntoIterator<Item = (BlockIndex, SudokuNumber)>,
+    ) -> Self {
+        self.eliminations.extend(eliminations);
+        self
+    }
+
+    pub fn with_coloring(
+        mut self,
+        coloring: impl IntoIterator<Item = (BlockIndex, SudokuNumber, CandidateColor)>,
+    ) -> Self {
+        self.coloring.extend(coloring);
+        self
+    }
+
+    pub fn with_chain(mut self, chain: Chain) -> Self {
+        self.chain = Some(chain);
+        self
+    }
+
+    pub fn with_assumptions(mut self, assumptions: impl IntoIterator<Item = Assumption>) -> Self {
+        self.assumptions.extend(assumptions);
+        self
+    }
+
+    /// The color of a source, if it's painted.
+    pub fn color_of(&self, index: &BlockIndex, number: SudokuNumber) -> Option<CandidateColor> {
+        self.coloring
+            .iter()
+            .find(|(i, n, _)| i == index && *n == number)
+            .map(|(_, _, color)| *color)
+    }
+
+    /// Blocks forming the pattern, each one only once.
+    pub fn source_blocks(&self) -> Vec<BlockIndex> {
+        unique_blocks(self.sources.iter().map(|(index, _)| index))
+    }
+
+    /// Blocks that are changed by this step, each one only once.
+    pub fn changed_blocks(&self) -> Vec<BlockIndex> {
+        unique_blocks(
+            self.placement
+                .iter()
+                .chain(self.eliminations.iter())
+                .map(|(index, _)| index),
+        )
+    }
+
+    /// Returns `true` if applying this step changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.placement.is_none() && self.eliminations.is_empty()
+    }
+}
+
+impl Display for SolveStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.strategy.name())?;
+        if let Some((container_type, number)) = &self.container {
+            write!(f, " in {} {}", container_type, number.to_u8())?;
+        }
+
+        if !self.base_sets.is_empty() {
+            f.write_str(" in")?;
+            write_sets(f, &self.base_sets)?;
+        }
+
+        if !self.cover_sets.is_empty() {
+            f.write_str(" covering")?;
+            write_sets(f, &self.cover_sets)?;
+        }
+
+        f.write_char(':')?;
+        if let Some(chain) = &self.chain {
+            write!(f, " {}", chain)?;
+        } else {
+            for (index, number) in &self.sources {
+                write!(f, " {}({})", index, number.to_u8())?;
+            }
+        }
+
+        if let Some((index, number)) = &self.placement {
+            write!(f, ", {} is {}", index, number.to_u8())?;
+        }
+
+        if !self.eliminations.is_empty() {
+            f.write_str(", removes")?;
+            for (index, number) in &self.eliminations {
+                write!(f, " {}({})", index, number.to_u8())?;
+            }
+        }
+
+        for assumption in &self.assumptions {
+            write!(f, "; {}", assumption)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A possible number that a strategy removes from a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elimination {
+    pub index: BlockIndex,
+    pub number: SudokuNumber,
+    /// The first block of the pattern causing this elimination.
+    pub source: Option<BlockIndex>,
+}
+
+/// Everything a strategy found on a board, without the board being changed.
+///
+/// Use [`SudokuBoard::preview_strategy`] to only mark the effects or
+/// [`SudokuBoard::apply_strategy`] to actually change the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StrategyOutcome {
+    pub strategy: Strategy,
+    pub steps: Vec<SolveStep>,
+}
+
+impl StrategyOutcome {
+    pub fn new(strategy: Strategy, steps: Vec<SolveStep>) -> Self {
+        Self { strategy, steps }
+    }
+
+    /// Numbers to be placed in blocks, each one only once.
+    pub fn placements(&self) -> Vec<(BlockIndex, SudokuNumber)> {
+        let mut placements: Vec<(BlockIndex, SudokuNumber)> = vec![];
+        for placement in self.steps.iter().filter_map(|f| f.placement.as_ref()) {
+            if !placements.contains(placement) {
+                placements.push(placement.clone());
+            }
+        }
+        placements
+    }
+
+    /// Possible numbers to be removed, each one only once.
+    pub fn eliminations(&self) -> Vec<Elimination> {
+        let mut eliminations: Vec<Elimination> = vec![];
+        for step in &self.steps {
+            let source = step.sources.first().map(|(index, _)| index.clone());
+            for (index, number) in &step.eliminations {
+                if !eliminations
+                    .iter()
+                    .any(|f| &f.index == index && f.number == *number)
+                {
+                    eliminations.push(Elimination {
+                        index: index.clone(),
+                        number: *number,
+                        source: source.clone(),
+                    });
+                }
+            }
+        }
+        eliminations
     }
+
+    /// Returns `true` if applying this outcome changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.steps.iter().all(|f| f.is_empty())
+    }
+}
+
+fn write_sets(
+    f: &mut std::fmt::Formatter<'_>,
+    sets: &[(ContainerType, SudokuNumber)],
+) -> std::fmt::Result {
+    for (index, (container_type, number)) in sets.iter().enumerate() {
+        let separator = if index == 0 { "" } else { "," };
+        write!(f, "{} {} {}", separator, container_type, number.to_u8())?;
+    }
+    Ok(())
+}
+
+fn unique_blocks<'a>(indexes: impl Iterator<Item = &'a BlockIndex>) -> Vec<BlockIndex> {
+    let mut blocks: Vec<BlockIndex> = vec![];
+    for index in indexes {
+        if !blocks.contains(index) {
+            blocks.push(index.clone());
+        }
+    }
+    blocks
+}
+
+/// Finds possible `number` in every block that sees the block at `index`.
+pub(crate) fn peers_having(
+    board: &SudokuBoard,
+    index: &BlockIndex,
+    number: SudokuNumber,
+) -> impl Iterator<Item = (BlockIndex, SudokuNumber)> {
+    board
+        .iter_peers(index)
+        .filter(move |f| {
+            f.status
+                .as_possibilities()
+                .is_some_and(|f| f.numbers.has_number(number))
+        })
+        .map(move |f| (f.index().clone(), number))
+}
+
+/// Finds possible `number` in every block that sees all the given blocks.
+pub(crate) fn common_peers_having(
+    board: &SudokuBoard,
+    indexes: &[&BlockIndex],
+    number: SudokuNumber,
+) -> Vec<(BlockIndex, SudokuNumber)> {
+    let Some((first, rest)) = indexes.split_first() else {
+        return vec![];
+    };
+
+    board
+        .iter_peers(first)
+        .filter(|f| rest.iter().all(|index| f.index().is_peer_of(index)))
+        .filter(|f| {
+            f.status
+                .as_possibilities()
+                .is_some_and(|f| f.numbers.has_number(number))
+        })
+        .map(|f| (f.index().clone(), number))
+        .collect()
+}
+
+/// Two candidates can't both be true if they're in the same block, or they're the same number
+/// in blocks that see each other.
+pub(crate) fn sees(
+    first: (&BlockIndex, SudokuNumber),
+    second: (&BlockIndex, SudokuNumber),
+) -> bool {
+    (first.0 == second.0 && first.1 != second.1)
+        || (first.1 == second.1 && first.0.is_peer_of(second.0))
+}
+
+/// Two blocks of a container that are the only places for a number, so one of them must be it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConjugatePair {
+    pub container: (ContainerType, SudokuNumber),
+    pub number: SudokuNumber,
+    pub blocks: [BlockIndex; 2],
+}
+
+impl ConjugatePair {
+    /// The other block of the pair, `None` if `index` is not in this pair.
+    pub fn other(&self, index: &BlockIndex) -> Option<&BlockIndex> {
+        match &self.blocks {
+            [first, second] if first == index => Some(second),
+            [first, second] if second == index => Some(first),
+            _ => None,
+        }
+    }
+}
+
+/// Finds every container of the given type where `number` is possible in exactly two blocks.
+pub fn conjugate_pairs(
+    board: &SudokuBoard,
+    container_type: ContainerType,
+    number: SudokuNumber,
+) -> Vec<ConjugatePair> {
+    SudokuNumber::ALL
+        .into_iter()
+        .filter_map(|container_number| {
+            let places = board
+                .iter_container(container_type, container_number)
+                .filter(|f| {
+                    f.status
+                        .as_possibilities()
+                        .is_some_and(|p| p.numbers.has_number(number))
+                })
+                .map(|f| f.index().clone())
+                .collect::<Vec<_>>();
+
+            let [first, second] = <[BlockIndex; 2]>::try_from(places).ok()?;
+            Some(ConjugatePair {
+                container: (container_type, container_number),
+                number,
+                blocks: [first, second],
+            })
+        })
+        .collect()
+}
+
+/// Every block having possible numbers, along with its numbers.
+pub(crate) fn possible_blocks(board: &SudokuBoard) -> Vec<(&BlockIndex, &SudokuNumbers)> {
+    board
+        .get_blocks()
+        .filter_map(|f| f.status.as_possibilities().map(|p| (f.index(), &p.numbers)))
+        .collect()
+}
+
+/// Finds the next step a player can take, trying simpler strategies first
+/// in the order of [`pipeline::StrategyPipeline::default`].
+///
+/// Only blocks with [`crate::SudokuBlockStatus::Possibilities`] are considered, so possibilities
+/// should be updated before asking for a step.
+pub fn next_step(board: &SudokuBoard) -> Option<SolveStep> {
+    pipeline::StrategyPipeline::default().next_step(board)
+}
+
+/// Every way of choosing `size` items out of `items`, keeping their order.
+pub(crate) fn combinations<T: Clone>(items: &[T], size: usize) -> Vec<Vec<T>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+
+    let mut result = vec![];
+    for (index, item) in items.iter().enumerate() {
+        for mut rest in combinations(&items[index + 1..], size - 1) {
+            rest.insert(0, item.clone());
+            result.push(rest);
+        }
+    }
+    result
 }
 
 pub trait SudokuSolvingStrategy {
-    const STRATEGY: Strategy;
+    fn strategy(&self) -> Strategy;
+
+    /// Finds every step this strategy can take on the board, without changing it.
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep>;
+
+    /// Returns `true` if the steps are only valid on boards with a unique solution.
+    fn requires_uniqueness(&self) -> bool {
+        false
+    }
+
+    /// Collects every step into a [`StrategyOutcome`].
+    fn outcome(&self, board: &SudokuBoard) -> StrategyOutcome {
+        StrategyOutcome::new(self.strategy(), self.find_steps(board))
+    }
+}
+
+/// Boards made only of possible numbers, shared by the strategy tests.
+#[cfg(test)]
+pub(crate) mod test_boards {
+    use crate::{
+        BlockIndex, Possibilities, SudokuBlockStatus, SudokuBoard,
+        numbers::{SudokuNumber, SudokuNumbers},
+    };
+
+    /// Every block has the numbers given by `numbers`.
+    pub(crate) fn board_with(numbers: impl Fn(&BlockIndex) -> SudokuNumbers) -> SudokuBoard {
+        let mut board = SudokuBoard::default();
+        for block in board.get_blocks_mut() {
+            let numbers = numbers(block.index());
+            block.status = SudokuBlockStatus::Possibilities(Possibilities::new(numbers));
+        }
+        board
+    }
+
+    /// Every block can have every number but 1, 2 and 3, except the given ones.
+    pub(crate) fn board_with_blocks(blocks: &[(BlockIndex, &[SudokuNumber])]) -> SudokuBoard {
+        use SudokuNumber::*;
+
+        board_with(|index| match blocks.iter().find(|(f, _)| f == index) {
+            Some((_, numbers)) => SudokuNumbers::new(numbers.iter().copied()),
+            None => SudokuNumbers::new([Four, Five, Six, Seven, Eight, Nine]),
+        })
+    }
+
+    /// Every block has every number, except blocks not in `places` can't be `number`.
+    pub(crate) fn board_with_number(
+        number: SudokuNumber,
+        places: impl Fn(&BlockIndex) -> bool,
+    ) -> SudokuBoard {
+        board_with(|index| {
+            let mut numbers = SudokuNumbers::new_all();
+            if !places(index) {
+                numbers.del_number(number);
+            }
+            numbers
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_step() {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST);
+        assert_eq!(next_step(&board), None);
+
+        board.update_possibilities();
+        let solution = board.solution().unwrap();
+        let step = next_step(&board).unwrap();
+
+        assert_eq!(step.strategy, Strategy::NakedSingle);
+
+        let (index, number) = step.placement.clone().unwrap();
+        assert_eq!(step.source_blocks(), vec![index.clone()]);
+        assert_eq!(
+            solution.get_block(&index).status.as_resolved(),
+            Some(&number)
+        );
+        assert!(
+            step.eliminations
+                .iter()
+                .all(|(f, n)| f.is_peer_of(&index) && *n == number)
+        );
+        assert_eq!(step.changed_blocks().len(), step.eliminations.len() + 1);
+        assert!(step.to_string().starts_with(&format!(
+            "Naked single: {}({}), {} is {}",
+            index,
+            number.to_u8(),
+            index,
+            number.to_u8()
+        )));
+    }
 
-    fn update_possible_numbers(&self, board: &mut SudokuBoard, show_only_effect: bool);
+    #[test]
+    fn test_next_step_uses_pipeline() {
+        use SudokuNumber::*;
+
+        // 6 in rows 2 and 7 is only possible in columns 3 and 8.
+        let board = test_boards::board_with_number(Six, |f| {
+            ![Two, Seven].contains(&f.row) || [Three, Eight].contains(&f.col)
+        });
+
+        assert_eq!(next_step(&board).unwrap().strategy, Strategy::XWing);
+    }
+
+    #[test]
+    fn test_steps_agree_with_solution() {
+        let mut board = SudokuBoard::from_u8(sudoku_samples::normal::FIRST);
+        board.update_possibilities();
+        let solution = board.solution().unwrap();
+
+        let steps = hidden_single::HiddenSingleStrategy
+            .find_steps(&board)
+            .into_iter()
+            .chain(naked_single::NakedSingleStrategy.find_steps(&board));
+
+        for step in steps {
+            let (index, number) = step.placement.unwrap();
+            assert_eq!(
+                solution.get_block(&index).status.as_resolved(),
+                Some(&number)
+            );
+
+            for (index, number) in step.eliminations {
+                assert_ne!(
+                    solution.get_block(&index).status.as_resolved(),
+                    Some(&number)
+                );
+            }
+        }
+    }
 }
@@ -1,85 +1,98 @@
-use std::collections::{HashMap, HashSet};
-
 use crate::{
-    BlockIndex, ContainerType,
-    numbers::{SudokuNumber, SudokuNumbers},
-    strategies::SudokuSolvingStrategy,
+    SudokuBoard,
+    strategies::{SolveStep, SudokuSolvingStrategy, naked_subset::NakedSubsetStrategy},
 };
 
+/// Naked subsets of two blocks, same as [`NakedSubsetStrategy::PAIR`].
 pub struct NakedPairStrategy;
 
 impl SudokuSolvingStrategy for NakedPairStrategy {
-    const STRATEGY: super::Strategy = super::Strategy::NakedPair;
+    fn strategy(&self) -> super::Strategy {
+        super::Strategy::NakedPair
+    }
 
-    fn update_possible_numbers(&self, board: &mut crate::SudokuBoard, show_only_effect: bool) {
-        for container in [
-            ContainerType::Row,
-            ContainerType::Column,
-            ContainerType::Square,
-        ] {
-            for index in SudokuNumber::ALL {
-                let mut grouping: HashMap<SudokuNumbers, HashSet<BlockIndex>> = HashMap::new();
-                for (block_index, poss) in board
-                    .get_container(container, index)
-                    .iter()
-                    .filter_map(|b| b.status.as_possibilities().map(|f| (b.index().clone(), f)))
-                {
-                    let group = grouping.entry(poss.numbers.clone()).or_default();
-                    group.insert(block_index);
-                }
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        NakedSubsetStrategy::PAIR.find_steps(board)
+    }
+}
 
-                for (numbers, indexes) in grouping {
-                    // This (condition below) means the count of blocks having exact possible numbers is as same
-                    // as the count of each one's possible numbers. And this means these n numbers are
-                    // only valid in these n blocks (So remove them from others)
-                    if numbers.count_numbers() == indexes.len() {
-                        board
-                            .get_container_mut(container, index)
-                            .iter_mut()
-                            .filter(|f| f.is_possibilities())
-                            .for_each(|block| {
-                                let index = block.index.clone();
-                                let poss = block.status.as_possibilities_mut().unwrap();
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BlockIndex, ContainerType, Possibilities, SudokuBlockStatus,
+        numbers::{SudokuNumber, SudokuNumbers},
+        strategies::StrategyEffect,
+    };
 
-                                if indexes.contains(&index) {
-                                    // This is a pair
-                                    for number in numbers.iter() {
-                                        if show_only_effect {
-                                            poss.update_strategy_marker(
-                                                number,
-                                                super::StrategyMarker {
-                                                    strategy: super::Strategy::NakedPair,
-                                                    effect: super::StrategyEffect::Source,
-                                                },
-                                            );
-                                        } else {
-                                            poss.clear_strategy_marker(number);
-                                        }
-                                    }
-                                } else {
-                                    // This is not a pair remove pair possibilities from it.
+    use super::*;
+    use SudokuNumber::*;
 
-                                    if !show_only_effect {
-                                        poss.numbers.del_numbers(numbers.iter());
-                                        for number in numbers.iter() {
-                                            poss.clear_strategy_marker(number);
-                                        }
-                                    } else {
-                                        for number in numbers.iter() {
-                                            poss.update_strategy_marker(
-                                                number,
-                                                super::StrategyMarker {
-                                                    strategy: super::Strategy::NakedPair,
-                                                    effect: super::StrategyEffect::Effected,
-                                                },
-                                            );
-                                        }
-                                    }
-                                }
-                            });
-                    }
-                }
-            }
+    /// First row has a naked pair of (2, 5) in 4th and 7th blocks, others have every number.
+    fn naked_pair_board() -> SudokuBoard {
+        let mut board = SudokuBoard::default();
+        for block in board.get_row_mut(One) {
+            block.status = SudokuBlockStatus::Possibilities(Possibilities::new(
+                if block.col() == Four || block.col() == Seven {
+                    SudokuNumbers::new([Two, Five])
+                } else {
+                    SudokuNumbers::new_all()
+                },
+            ));
         }
+        board
+    }
+
+    #[test]
+    fn test_find_naked_pair() {
+        let steps = NakedPairStrategy.find_steps(&naked_pair_board());
+        assert_eq!(steps.len(), 1);
+
+        let step = &steps[0];
+        assert_eq!(step.container, Some((ContainerType::Row, One)));
+        assert_eq!(
+            step.source_blocks(),
+            vec![BlockIndex::new(One, Four), BlockIndex::new(One, Seven)]
+        );
+        assert_eq!(step.eliminations.len(), 14);
+        assert!(
+            step.eliminations
+                .iter()
+                .all(|(index, number)| [Two, Five].contains(number)
+                    && ![Four, Seven].contains(&index.col))
+        );
+    }
+
+    #[test]
+    fn test_preview_and_apply() {
+        let mut board = naked_pair_board();
+        let outcome = NakedPairStrategy.outcome(&board);
+
+        board.preview_strategy(&outcome);
+
+        let pair = board.get_block(&BlockIndex::new(One, Four));
+        let pair = pair.status.as_possibilities().unwrap();
+        assert!(pair.has_strategy_effect(&Two).unwrap().is_source());
+        assert!(pair.has_strategy_effect(&Five).unwrap().is_source());
+
+        let other = board.get_block(&BlockIndex::new(One, One));
+        let other = other.status.as_possibilities().unwrap();
+        assert_eq!(other.numbers.count_numbers(), 9);
+        assert_eq!(
+            other.has_strategy_effect(&Five).unwrap().effect(),
+            &StrategyEffect::Effected {
+                index: BlockIndex::new(One, Four),
+                number: Some(Five)
+            }
+        );
+        assert!(other.has_strategy_effect(&One).is_none());
+
+        board.apply_strategy(&outcome);
+
+        let other = board.get_block(&BlockIndex::new(One, One));
+        let other = other.status.as_possibilities().unwrap();
+        assert_eq!(other.numbers.count_numbers(), 7);
+        assert!(!other.numbers.has_number(Two));
+        assert!(other.has_strategy_effect(&Five).is_none());
+        assert!(NakedPairStrategy.outcome(&board).is_empty());
     }
 }
@@ -1,9 +1,98 @@
-use crate::strategies::SudokuSolvingStrategy;
+use crate::strategies::{SolveStep, SudokuSolvingStrategy, peers_having};
 
 pub struct NakedSingleStrategy;
 
 impl SudokuSolvingStrategy for NakedSingleStrategy {
-    const STRATEGY: super::Strategy = super::Strategy::NakedSingle;
+    fn strategy(&self) -> super::Strategy {
+        super::Strategy::NakedSingle
+    }
 
-    fn update_possible_numbers(&self, _board: &mut crate::SudokuBoard, _show_only_effect: bool) {}
+    fn find_steps(&self, board: &crate::SudokuBoard) -> Vec<SolveStep> {
+        board
+            .get_blocks()
+            .filter_map(|block| {
+                let number = block.status.as_possibilities()?.numbers.single()?;
+                Some(
+                    SolveStep::new(self.strategy())
+                        .with_sources([(block.index().clone(), number)])
+                        .with_placement(block.index().clone(), number)
+                        .with_eliminations(peers_having(board, block.index(), number)),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BlockIndex, SudokuBlockStatus,
+        numbers::{SudokuNumber, SudokuNumbers},
+        strategies::{StrategyEffect, test_boards::board_with},
+    };
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_preview_and_apply() {
+        // The first block can only be 7, others can be anything.
+        let mut board = board_with(|index| {
+            if index == &BlockIndex::new(One, One) {
+                SudokuNumbers::new([Seven])
+            } else {
+                SudokuNumbers::new_all()
+            }
+        });
+        let outcome = NakedSingleStrategy.outcome(&board);
+        assert_eq!(outcome.steps.len(), 1);
+        assert_eq!(
+            outcome.placements(),
+            vec![(BlockIndex::new(One, One), Seven)]
+        );
+        // 8 in the row, 8 in the column and 4 more in the square.
+        assert_eq!(outcome.eliminations().len(), 20);
+
+        board.preview_strategy(&outcome);
+
+        let single = board.get_block(&BlockIndex::new(One, One));
+        let single = single.status.as_possibilities().unwrap();
+        assert!(single.has_strategy_effect(&Seven).unwrap().is_source());
+
+        let peer = board.get_block(&BlockIndex::new(Two, Two));
+        let peer = peer.status.as_possibilities().unwrap();
+        assert_eq!(
+            peer.has_strategy_effect(&Seven).unwrap().effect(),
+            &StrategyEffect::Effected {
+                index: BlockIndex::new(One, One),
+                number: Some(Seven)
+            }
+        );
+        let other = board.get_block(&BlockIndex::new(Five, Five));
+        assert!(
+            other
+                .status
+                .as_possibilities()
+                .unwrap()
+                .has_strategy_effect(&Seven)
+                .is_none()
+        );
+
+        board.apply_strategy(&outcome);
+
+        assert_eq!(
+            board.get_block(&BlockIndex::new(One, One)).status,
+            SudokuBlockStatus::Resolved(Seven)
+        );
+        let peer = board.get_block(&BlockIndex::new(Two, Two));
+        assert!(
+            !peer
+                .status
+                .as_possibilities()
+                .unwrap()
+                .numbers
+                .has_number(Seven)
+        );
+        assert!(NakedSingleStrategy.outcome(&board).is_empty());
+    }
 }
@@ -0,0 +1,146 @@
+use crate::{
+    ContainerType, SudokuBoard,
+    numbers::{SudokuNumber, SudokuNumbers},
+    strategies::{SolveStep, Strategy, SudokuSolvingStrategy, combinations},
+};
+
+/// Finds `size` blocks of a container that together can only have `size` numbers,
+/// so those numbers can be removed from every other block of the container.
+///
+/// Blocks don't need to have the same possible numbers, only their union matters
+/// (`{1, 2}`, `{2, 3}` and `{1, 3}` is a naked triple).
+pub struct NakedSubsetStrategy {
+    size: usize,
+}
+
+impl NakedSubsetStrategy {
+    pub const PAIR: NakedSubsetStrategy = NakedSubsetStrategy { size: 2 };
+    pub const TRIPLE: NakedSubsetStrategy = NakedSubsetStrategy { size: 3 };
+    pub const QUAD: NakedSubsetStrategy = NakedSubsetStrategy { size: 4 };
+}
+
+impl SudokuSolvingStrategy for NakedSubsetStrategy {
+    fn strategy(&self) -> Strategy {
+        match self.size {
+            2 => Strategy::NakedPair,
+            3 => Strategy::NakedTriple,
+            _ => Strategy::NakedQuad,
+        }
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for container in ContainerType::ALL {
+            for container_number in SudokuNumber::ALL {
+                let blocks = board
+                    .iter_container(container, container_number)
+                    .filter_map(|f| f.status.as_possibilities().map(|p| (f.index(), &p.numbers)))
+                    .collect::<Vec<_>>();
+
+                let candidates = blocks
+                    .iter()
+                    .filter(|(_, numbers)| (2..=self.size).contains(&numbers.count_numbers()))
+                    .copied()
+                    .collect::<Vec<_>>();
+
+                for subset in combinations(&candidates, self.size) {
+                    let numbers = subset.iter().fold(SudokuNumbers::default(), |mut acc, f| {
+                        acc.set_numbers(f.1.iter());
+                        acc
+                    });
+
+                    if numbers.count_numbers() != self.size {
+                        continue;
+                    }
+
+                    let eliminations = blocks
+                        .iter()
+                        .filter(|(index, _)| !subset.iter().any(|(f, _)| f == index))
+                        .flat_map(|(index, possibles)| {
+                            numbers
+                                .iter()
+                                .filter(|f| possibles.has_number(*f))
+                                .map(|f| ((*index).clone(), f))
+                        })
+                        .collect::<Vec<_>>();
+
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_container(container, container_number)
+                            .with_sources(subset.iter().flat_map(|(index, possibles)| {
+                                possibles.iter().map(|f| ((*index).clone(), f))
+                            }))
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlockIndex, strategies::test_boards::board_with};
+
+    use super::*;
+    use SudokuNumber::*;
+
+    /// First row has a naked triple of {1, 2}, {2, 3} and {1, 3}, others have every number.
+    fn naked_triple(index: &BlockIndex) -> SudokuNumbers {
+        match (index.row, index.col) {
+            (One, Two) => SudokuNumbers::new([One, Two]),
+            (One, Five) => SudokuNumbers::new([Two, Three]),
+            (One, Nine) => SudokuNumbers::new([One, Three]),
+            _ => SudokuNumbers::new_all(),
+        }
+    }
+
+    #[test]
+    fn test_find_naked_triple() {
+        let board = board_with(naked_triple);
+        assert!(NakedSubsetStrategy::PAIR.find_steps(&board).is_empty());
+
+        let steps = NakedSubsetStrategy::TRIPLE.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+
+        let step = &steps[0];
+        assert_eq!(step.strategy, Strategy::NakedTriple);
+        assert_eq!(step.container, Some((ContainerType::Row, One)));
+        assert_eq!(
+            step.source_blocks(),
+            vec![
+                BlockIndex::new(One, Two),
+                BlockIndex::new(One, Five),
+                BlockIndex::new(One, Nine)
+            ]
+        );
+        // Three numbers from the six other blocks of the row.
+        assert_eq!(step.eliminations.len(), 18);
+        assert!(
+            step.eliminations
+                .iter()
+                .all(|(index, number)| index.row == One && [One, Two, Three].contains(number))
+        );
+    }
+
+    #[test]
+    fn test_apply_naked_triple() {
+        let mut board = board_with(naked_triple);
+        let outcome = NakedSubsetStrategy::TRIPLE.outcome(&board);
+        board.apply_strategy(&outcome);
+
+        let other = board.get_block(&BlockIndex::new(One, One));
+        assert_eq!(
+            other.status.as_possibilities().unwrap().numbers,
+            SudokuNumbers::new([Four, Five, Six, Seven, Eight, Nine])
+        );
+        assert!(NakedSubsetStrategy::TRIPLE.outcome(&board).is_empty());
+    }
+}
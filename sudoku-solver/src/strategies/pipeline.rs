@@ -0,0 +1,338 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    BlockIndex, SudokuBoard,
+    strategies::{
+        SolveStep, Strategy, StrategyOutcome, SudokuSolvingStrategy,
+        als::{AlsXzStrategy, DeathBlossomStrategy},
+        chains::ChainStrategy,
+        coloring::{MedusaStrategy, SimpleColoringStrategy},
+        fish::FishStrategy,
+        forcing::{CellForcingChainStrategy, NishioStrategy, UnitForcingChainStrategy},
+        hidden_single::HiddenSingleStrategy,
+        hidden_subset::HiddenSubsetStrategy,
+        locked_candidates::LockedCandidatesStrategy,
+        naked_pair::NakedPairStrategy,
+        naked_single::NakedSingleStrategy,
+        naked_subset::NakedSubsetStrategy,
+        single_digit::{EmptyRectangleStrategy, SkyscraperStrategy, TwoStringKiteStrategy},
+        uniqueness::{BivalueUniversalGraveStrategy, UniqueRectangleStrategy},
+        wings::{WWingStrategy, XYWingStrategy, XYZWingStrategy},
+    },
+};
+
+/// A strategy that can be stored and shared at runtime.
+pub type SharedStrategy = Arc<dyn SudokuSolvingStrategy + Send + Sync>;
+
+/// Returns the built-in implementation of a [`Strategy`].
+pub fn builtin(strategy: Strategy) -> SharedStrategy {
+    match strategy {
+        Strategy::NakedSingle => Arc::new(NakedSingleStrategy),
+        Strategy::HiddenSingle => Arc::new(HiddenSingleStrategy),
+        Strategy::LockedCandidates => Arc::new(LockedCandidatesStrategy),
+        Strategy::NakedPair => Arc::new(NakedPairStrategy),
+        Strategy::HiddenPair => Arc::new(HiddenSubsetStrategy::PAIR),
+        Strategy::NakedTriple => Arc::new(NakedSubsetStrategy::TRIPLE),
+        Strategy::NakedQuad => Arc::new(NakedSubsetStrategy::QUAD),
+        Strategy::XWing => Arc::new(FishStrategy::X_WING),
+        Strategy::Swordfish => Arc::new(FishStrategy::SWORDFISH),
+        Strategy::Jellyfish => Arc::new(FishStrategy::JELLYFISH),
+        Strategy::XYWing => Arc::new(XYWingStrategy),
+        Strategy::XYZWing => Arc::new(XYZWingStrategy),
+        Strategy::WWing => Arc::new(WWingStrategy),
+        Strategy::Skyscraper => Arc::new(SkyscraperStrategy),
+        Strategy::TwoStringKite => Arc::new(TwoStringKiteStrategy),
+        Strategy::EmptyRectangle => Arc::new(EmptyRectangleStrategy),
+        Strategy::HiddenTriple => Arc::new(HiddenSubsetStrategy::TRIPLE),
+        Strategy::HiddenQuad => Arc::new(HiddenSubsetStrategy::QUAD),
+        Strategy::SimpleColoring => Arc::new(SimpleColoringStrategy),
+        Strategy::Medusa => Arc::new(MedusaStrategy),
+        Strategy::XChain => Arc::new(ChainStrategy::X_CHAIN),
+        Strategy::XYChain => Arc::new(ChainStrategy::XY_CHAIN),
+        Strategy::AlternatingInferenceChain => Arc::new(ChainStrategy::AIC),
+        Strategy::UniqueRectangle => Arc::new(UniqueRectangleStrategy),
+        Strategy::BivalueUniversalGrave => Arc::new(BivalueUniversalGraveStrategy),
+        Strategy::AlsXz => Arc::new(AlsXzStrategy),
+        Strategy::DeathBlossom => Arc::new(DeathBlossomStrategy),
+        Strategy::CellForcingChain => Arc::new(CellForcingChainStrategy),
+        Strategy::UnitForcingChain => Arc::new(UnitForcingChainStrategy),
+        Strategy::Nishio => Arc::new(NishioStrategy),
+    }
+}
+
+/// Maps every [`Strategy`] to an implementation, so they can be looked up at runtime.
+#[derive(Clone)]
+pub struct StrategyRegistry {
+    strategies: HashMap<Strategy, SharedStrategy>,
+}
+
+impl Default for StrategyRegistry {
+    /// A registry containing every built-in strategy.
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for strategy in Strategy::ALL {
+            registry.register(builtin(strategy));
+        }
+        registry
+    }
+}
+
+impl std::fmt::Debug for StrategyRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.strategies.keys()).finish()
+    }
+}
+
+impl StrategyRegistry {
+    pub fn empty() -> Self {
+        Self {
+            strategies: HashMap::new(),
+        }
+    }
+
+    /// Registers the implementation, replacing any previous one for the same [`Strategy`].
+    pub fn register(&mut self, solver: SharedStrategy) {
+        self.strategies.insert(solver.strategy(), solver);
+    }
+
+    pub fn get(&self, strategy: Strategy) -> Option<&SharedStrategy> {
+        self.strategies.get(&strategy)
+    }
+
+    /// Creates a pipeline using the given order, strategies missing from the registry are skipped.
+    pub fn pipeline(&self, order: impl IntoIterator<Item = Strategy>) -> StrategyPipeline {
+        StrategyPipeline::new(
+            order
+                .into_iter()
+                .filter_map(|strategy| self.get(strategy).cloned()),
+        )
+    }
+}
+
+/// An ordered list of strategies, from the simplest to the hardest.
+#[derive(Clone)]
+pub struct StrategyPipeline {
+    strategies: Vec<SharedStrategy>,
+    /// Enables strategies that require the board to have a unique solution.
+    assume_unique: bool,
+}
+
+impl Default for StrategyPipeline {
+    /// Every built-in strategy in the order of [`Strategy::ALL`].
+    fn default() -> Self {
+        StrategyRegistry::default().pipeline(Strategy::ALL)
+    }
+}
+
+impl std::fmt::Debug for StrategyPipeline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.strategies.iter().map(|f| f.strategy()))
+            .finish()
+    }
+}
+
+impl StrategyPipeline {
+    pub fn new(strategies: impl IntoIterator<Item = SharedStrategy>) -> Self {
+        Self {
+            strategies: strategies.into_iter().collect(),
+            assume_unique: false,
+        }
+    }
+
+    /// Set to `true` only if the board is known to have a unique solution,
+    /// otherwise [`SudokuSolvingStrategy::requires_uniqueness`] strategies are skipped.
+    pub fn assuming_unique(mut self, assume_unique: bool) -> Self {
+        self.assume_unique = assume_unique;
+        self
+    }
+
+    pub fn strategies(&self) -> impl Iterator<Item = Strategy> + '_ {
+        self.strategies.iter().map(|f| f.strategy())
+    }
+
+    /// The first step (in order) that can do something on the board, without applying it.
+    pub fn next_step(&self, board: &SudokuBoard) -> Option<SolveStep> {
+        self.strategies
+            .iter()
+            .filter(|f| self.assume_unique || !f.requires_uniqueness())
+            .flat_map(|f| f.find_steps(board))
+            .find(|f| !f.is_empty())
+    }
+
+    /// Applies the first strategy (in order) that can do something on the board.
+    ///
+    /// Returns the applied outcome, or `None` if no strategy can make progress.
+    pub fn step(&self, board: &mut SudokuBoard) -> Option<StrategyOutcome> {
+        let outcome = self
+            .strategies
+            .iter()
+            .filter(|f| self.assume_unique || !f.requires_uniqueness())
+            .map(|f| f.outcome(board))
+            .find(|f| !f.is_empty())?;
+
+        board.apply_strategy(&outcome);
+        Some(outcome)
+    }
+
+    /// Keeps applying strategies, going back to the simplest one after each success,
+    /// until the board is solved or no strategy can make progress.
+    pub fn run(&self, board: &mut SudokuBoard) -> PipelineReport {
+        if board
+            .get_blocks()
+            .any(|f| matches!(f.status, crate::SudokuBlockStatus::Unresolved))
+        {
+            board.update_possibilities();
+        }
+
+        let mut applied = Vec::new();
+        while !is_solved(board) {
+            match self.step(board) {
+                Some(outcome) => applied.push(outcome),
+                None => break,
+            }
+        }
+
+        let state = if is_solved(board) {
+            PipelineState::Solved
+        } else {
+            PipelineState::Stuck {
+                unresolved: board
+                    .get_blocks()
+                    .filter(|f| !f.is_fixed() && !f.is_resolved())
+                    .map(|f| f.index().clone())
+                    .collect(),
+            }
+        };
+
+        PipelineReport { applied, state }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PipelineState {
+    /// Every block is fixed or resolved.
+    Solved,
+    /// No strategy could make progress.
+    Stuck {
+        /// Blocks that are still left unresolved.
+        unresolved: Vec<BlockIndex>,
+    },
+}
+
+/// The result of [`StrategyPipeline::run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PipelineReport {
+    /// Every applied outcome, in order.
+    pub applied: Vec<StrategyOutcome>,
+    pub state: PipelineState,
+}
+
+impl PipelineReport {
+    pub fn is_solved(&self) -> bool {
+        self.state == PipelineState::Solved
+    }
+
+    /// How many (non empty) steps of each strategy were applied.
+    pub fn strategy_counts(&self) -> HashMap<Strategy, usize> {
+        let mut counts = HashMap::new();
+        for outcome in &self.applied {
+            *counts.entry(outcome.strategy).or_default() +=
+                outcome.steps.iter().filter(|f| !f.is_empty()).count();
+        }
+        counts
+    }
+}
+
+fn is_solved(board: &SudokuBoard) -> bool {
+    board.get_blocks().all(|f| f.is_fixed() || f.is_resolved())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{difficulty::SudokuBoardDifficulty, generator::generate_from_seed};
+
+    use super::*;
+
+    #[test]
+    fn test_pipeline_solves_samples() {
+        for sample in [sudoku_samples::easy::FIRST, sudoku_samples::normal::FIRST] {
+            let mut board = SudokuBoard::from_u8(sample);
+            let report = StrategyPipeline::default().run(&mut board);
+
+            assert!(report.is_solved());
+            assert!(board.verify_board());
+            assert_eq!(board, SudokuBoard::from_u8(sample).solution().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_pipeline_stuck() {
+        let mut board = SudokuBoard::default();
+        let report = StrategyPipeline::default().run(&mut board);
+
+        assert!(report.applied.is_empty());
+        assert_eq!(
+            report.state,
+            PipelineState::Stuck {
+                unresolved: board.get_blocks().map(|f| f.index().clone()).collect()
+            }
+        );
+    }
+
+    /// Every strategy, at every stage of solving, must agree with the actual solution.
+    #[test]
+    fn test_strategies_are_sound() {
+        let registry = StrategyRegistry::default();
+        let pipeline = StrategyPipeline::default().assuming_unique(true);
+
+        for seed in 0..4 {
+            let mut board =
+                SudokuBoard::from_u8(generate_from_seed(seed, SudokuBoardDifficulty::Expert));
+            let solution = board.solution().unwrap();
+            board.update_possibilities();
+
+            loop {
+                for strategy in Strategy::ALL {
+                    let outcome = registry.get(strategy).unwrap().outcome(&board);
+                    for (index, number) in outcome.placements() {
+                        assert_eq!(
+                            solution.get_block(&index).status.as_resolved(),
+                            Some(&number),
+                            "{strategy:?} placed a wrong number"
+                        );
+                    }
+
+                    for elimination in outcome.eliminations() {
+                        assert_ne!(
+                            solution.get_block(&elimination.index).status.as_resolved(),
+                            Some(&elimination.number),
+                            "{strategy:?} removed a correct number"
+                        );
+                    }
+                }
+
+                if pipeline.step(&mut board).is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = StrategyRegistry::default();
+        for strategy in Strategy::ALL {
+            assert_eq!(registry.get(strategy).unwrap().strategy(), strategy);
+        }
+
+        let pipeline = StrategyRegistry::empty().pipeline(Strategy::ALL);
+        assert_eq!(pipeline.strategies().count(), 0);
+
+        let pipeline = registry.pipeline([Strategy::HiddenSingle]);
+        assert_eq!(
+            pipeline.strategies().collect::<Vec<_>>(),
+            [Strategy::HiddenSingle]
+        );
+    }
+}
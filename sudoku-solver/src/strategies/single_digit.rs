@@ -0,0 +1,332 @@
+//! Patterns built from strong links ([`ConjugatePair`]s) of a single number.
+
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::SudokuNumber,
+    strategies::{
+        ConjugatePair, SolveStep, Strategy, SudokuSolvingStrategy, combinations,
+        common_peers_having, conjugate_pairs,
+    },
+};
+
+/// Two parallel strong links (both in rows or both in columns) having one end on the same line.
+/// One of the other two ends must be the number, so it's removed from blocks seeing both.
+pub struct SkyscraperStrategy;
+
+impl SudokuSolvingStrategy for SkyscraperStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::Skyscraper
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for number in SudokuNumber::ALL {
+            for (base, cover) in [
+                (ContainerType::Row, ContainerType::Column),
+                (ContainerType::Column, ContainerType::Row),
+            ] {
+                let pairs = conjugate_pairs(board, base, number);
+
+                for links in combinations(&pairs, 2) {
+                    let [first, second] = &links[..] else {
+                        continue;
+                    };
+
+                    for (first_base, second_base) in ends(first, second) {
+                        if first_base.container_number(cover) != second_base.container_number(cover)
+                        {
+                            continue;
+                        }
+
+                        let first_end = first.other(first_base).unwrap();
+                        let second_end = second.other(second_base).unwrap();
+                        if first_end.container_number(cover) == second_end.container_number(cover) {
+                            // That's an X-Wing.
+                            continue;
+                        }
+
+                        let eliminations =
+                            common_peers_having(board, &[first_end, second_end], number);
+                        if eliminations.is_empty() {
+                            continue;
+                        }
+
+                        steps.push(
+                            SolveStep::new(self.strategy())
+                                .with_sources(
+                                    [first_base, first_end, second_base, second_end]
+                                        .map(|f| (f.clone(), number)),
+                                )
+                                .with_sets([first.container, second.container], [])
+                                .with_eliminations(eliminations),
+                        );
+                    }
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// A strong link in a row and one in a column, having one end of each in the same square.
+/// One of the other two ends must be the number, so it's removed from blocks seeing both.
+pub struct TwoStringKiteStrategy;
+
+impl SudokuSolvingStrategy for TwoStringKiteStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::TwoStringKite
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for number in SudokuNumber::ALL {
+            let rows = conjugate_pairs(board, ContainerType::Row, number);
+            let cols = conjugate_pairs(board, ContainerType::Column, number);
+
+            for row in &rows {
+                for col in &cols {
+                    for (row_base, col_base) in ends(row, col) {
+                        if row_base == col_base
+                            || row_base.square_number() != col_base.square_number()
+                        {
+                            continue;
+                        }
+
+                        let row_end = row.other(row_base).unwrap();
+                        let col_end = col.other(col_base).unwrap();
+                        if row_end == col_base || col_end == row_base {
+                            continue;
+                        }
+
+                        let eliminations = common_peers_having(board, &[row_end, col_end], number);
+                        if eliminations.is_empty() {
+                            continue;
+                        }
+
+                        steps.push(
+                            SolveStep::new(self.strategy())
+                                .with_container(ContainerType::Square, row_base.square_number())
+                                .with_sources(
+                                    [row_end, row_base, col_base, col_end]
+                                        .map(|f| (f.clone(), number)),
+                                )
+                                .with_sets([row.container, col.container], [])
+                                .with_eliminations(eliminations),
+                        );
+                    }
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// A square where a number is only possible on one row and one column of it (the cross),
+/// and a strong link having one end on the cross row (or column) outside of the square.
+///
+/// The number is removed from the block where the other end's row (or column) meets the
+/// cross column (or row), otherwise the square would have nowhere left for the number.
+pub struct EmptyRectangleStrategy;
+
+impl SudokuSolvingStrategy for EmptyRectangleStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::EmptyRectangle
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for number in SudokuNumber::ALL {
+            let links = [ContainerType::Row, ContainerType::Column]
+                .into_iter()
+                .flat_map(|f| conjugate_pairs(board, f, number))
+                .collect::<Vec<_>>();
+
+            for square in SudokuNumber::ALL {
+                let places = board
+                    .get_square(square)
+                    .filter(|f| {
+                        f.status
+                            .as_possibilities()
+                            .is_some_and(|p| p.numbers.has_number(number))
+                    })
+                    .map(|f| f.index().clone())
+                    .collect::<Vec<_>>();
+
+                if places.len() < 2
+                    || places.iter().all(|f| f.row == places[0].row)
+                    || places.iter().all(|f| f.col == places[0].col)
+                {
+                    continue;
+                }
+
+                for (row, col) in SudokuNumber::iter_numbers().filter(|(row, col)| {
+                    SudokuBoard::square_number(*row, *col) == square
+                        && places.iter().all(|f| f.row == *row || f.col == *col)
+                }) {
+                    for link in &links {
+                        let Some((target, link_base)) = empty_rectangle_target(link, row, col)
+                        else {
+                            continue;
+                        };
+                        if link.blocks.iter().any(|f| f.square_number() == square)
+                            || target.square_number() == square
+                        {
+                            continue;
+                        }
+
+                        let has_number = board
+                            .get_block(&target)
+                            .status
+                            .as_possibilities()
+                            .is_some_and(|p| p.numbers.has_number(number));
+                        if !has_number {
+                            continue;
+                        }
+
+                        steps.push(
+                            SolveStep::new(self.strategy())
+                                .with_container(ContainerType::Square, square)
+                                .with_sources(places.iter().map(|f| (f.clone(), number)))
+                                .with_sources([
+                                    (link_base.clone(), number),
+                                    (link.other(link_base).unwrap().clone(), number),
+                                ])
+                                .with_sets([link.container], [])
+                                .with_eliminations([(target, number)]),
+                        );
+                    }
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// For a strong link in a column, one end must be on the cross row, the target is on the other
+/// end's row and the cross column (and the other way around for rows).
+fn empty_rectangle_target(
+    link: &ConjugatePair,
+    row: SudokuNumber,
+    col: SudokuNumber,
+) -> Option<(BlockIndex, &BlockIndex)> {
+    let base = link.blocks.iter().find(|f| match link.container.0 {
+        ContainerType::Column => f.row == row,
+        _ => f.col == col,
+    })?;
+    let end = link.other(base)?;
+
+    match link.container.0 {
+        ContainerType::Column => Some((BlockIndex::new(end.row, col), base)),
+        _ => Some((BlockIndex::new(row, end.col), base)),
+    }
+}
+
+/// Every way of choosing one block of each pair as the connected ends.
+fn ends<'p>(
+    first: &'p ConjugatePair,
+    second: &'p ConjugatePair,
+) -> impl Iterator<Item = (&'p BlockIndex, &'p BlockIndex)> {
+    first
+        .blocks
+        .iter()
+        .flat_map(move |f| second.blocks.iter().map(move |s| (f, s)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::strategies::test_boards::board_with_number;
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_conjugate_pairs() {
+        let board = board_with_number(One, |f| f.row != One || [Two, Seven].contains(&f.col));
+        let pairs = conjugate_pairs(&board, ContainerType::Row, One);
+
+        assert_eq!(
+            pairs,
+            vec![ConjugatePair {
+                container: (ContainerType::Row, One),
+                number: One,
+                blocks: [BlockIndex::new(One, Two), BlockIndex::new(One, Seven)]
+            }]
+        );
+        assert_eq!(
+            pairs[0].other(&BlockIndex::new(One, Two)),
+            Some(&BlockIndex::new(One, Seven))
+        );
+        assert!(conjugate_pairs(&board, ContainerType::Column, One).is_empty());
+    }
+
+    #[test]
+    fn test_skyscraper() {
+        // Rows 1 and 5 have 1 in columns (2, 4) and (2, 6).
+        let board = board_with_number(One, |f| match f.row {
+            One => [Two, Four].contains(&f.col),
+            Five => [Two, Six].contains(&f.col),
+            _ => true,
+        });
+        let steps = SkyscraperStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].source_blocks(),
+            vec![
+                BlockIndex::new(One, Two),
+                BlockIndex::new(One, Four),
+                BlockIndex::new(Five, Two),
+                BlockIndex::new(Five, Six)
+            ]
+        );
+        assert_eq!(
+            steps[0].eliminations,
+            vec![
+                (BlockIndex::new(Two, Six), One),
+                (BlockIndex::new(Three, Six), One),
+                (BlockIndex::new(Four, Four), One),
+                (BlockIndex::new(Six, Four), One)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_string_kite() {
+        // Row 1 has 1 in columns 2 and 8, column 3 has it in rows 2 and 7.
+        let board = board_with_number(One, |f| match (f.row, f.col) {
+            (One, col) => [Two, Eight].contains(&col),
+            (_, Three) => [Two, Seven].contains(&f.row),
+            _ => true,
+        });
+        let steps = TwoStringKiteStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].container, Some((ContainerType::Square, One)));
+        assert_eq!(
+            steps[0].eliminations,
+            vec![(BlockIndex::new(Seven, Eight), One)]
+        );
+    }
+
+    #[test]
+    fn test_empty_rectangle() {
+        // Square 1 has 1 only on row 2 and column 2, column 7 has 1 in rows 2 and 8.
+        let board = board_with_number(One, |f| match (f.square_number(), f.col) {
+            (One, _) => f.row == Two || f.col == Two,
+            (_, Seven) => [Two, Eight].contains(&f.row),
+            _ => true,
+        });
+        let steps = EmptyRectangleStrategy.find_steps(&board);
+        assert!(!steps.is_empty());
+        assert!(
+            steps
+                .iter()
+                .any(|f| f.eliminations == vec![(BlockIndex::new(Eight, Two), One)])
+        );
+    }
+}
@@ -0,0 +1,446 @@
+//! Strategies that only work on boards with a unique solution. They avoid deadly patterns,
+//! blocks whose numbers could be swapped around and still leave a valid board.
+
+use crate::{
+    BlockIndex, ContainerType, SudokuBoard,
+    numbers::{SudokuNumber, SudokuNumbers},
+    strategies::{
+        SolveStep, Strategy, SudokuSolvingStrategy, combinations, common_peers_having,
+        peers_having, possible_blocks,
+    },
+};
+
+/// Four blocks on two rows, two columns and two squares, all having the same two numbers
+/// `{a, b}`. If they could only be `a` or `b`, the two would be swappable, so something else
+/// must be true in one of the blocks (the roof), depending on the type:
+///
+/// 1. Only one block has other numbers, `a` and `b` are removed from it.
+/// 2. Two blocks have the same extra number `c`, one of them is `c` so it's removed from
+///    blocks seeing both.
+/// 3. Two blocks seeing each other have extra numbers, that form a naked subset with other
+///    blocks of a shared container.
+/// 4. Two blocks seeing each other are the only places of `a` in a shared container, so they
+///    can't be `b`.
+pub struct UniqueRectangleStrategy;
+
+impl SudokuSolvingStrategy for UniqueRectangleStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::UniqueRectangle
+    }
+
+    fn requires_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+
+        for rows in combinations(&SudokuNumber::ALL, 2) {
+            for cols in combinations(&SudokuNumber::ALL, 2) {
+                let corners = [
+                    BlockIndex::new(rows[0], cols[0]),
+                    BlockIndex::new(rows[0], cols[1]),
+                    BlockIndex::new(rows[1], cols[0]),
+                    BlockIndex::new(rows[1], cols[1]),
+                ];
+
+                let mut squares = corners
+                    .iter()
+                    .map(|f| f.square_number())
+                    .collect::<Vec<_>>();
+                squares.sort();
+                squares.dedup();
+                if squares.len() != 2 {
+                    continue;
+                }
+
+                let Some(numbers) = corners
+                    .iter()
+                    .map(|f| numbers_of(board, f).cloned())
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+
+                let common = SudokuNumber::ALL
+                    .into_iter()
+                    .filter(|n| numbers.iter().all(|f| f.has_number(*n)))
+                    .collect::<Vec<_>>();
+
+                for pair in combinations(&common, 2) {
+                    let pair = SudokuNumbers::new(pair);
+                    let (floors, roofs): (Vec<_>, Vec<_>) = corners
+                        .iter()
+                        .zip(&numbers)
+                        .partition(|(_, numbers)| **numbers == pair);
+
+                    let sources = corners
+                        .iter()
+                        .flat_map(|f| pair.iter().map(move |n| (f.clone(), n)))
+                        .collect::<Vec<_>>();
+
+                    for step in rectangle_steps(board, &pair, &floors, &roofs) {
+                        steps.push(step.with_sources(sources.clone()));
+                    }
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+fn numbers_of<'b>(board: &'b SudokuBoard, index: &BlockIndex) -> Option<&'b SudokuNumbers> {
+    board
+        .get_block(index)
+        .status
+        .as_possibilities()
+        .map(|f| &f.numbers)
+}
+
+/// Steps of every type that the rectangle matches, without the corners as sources.
+fn rectangle_steps(
+    board: &SudokuBoard,
+    pair: &SudokuNumbers,
+    floors: &[(&BlockIndex, &SudokuNumbers)],
+    roofs: &[(&BlockIndex, &SudokuNumbers)],
+) -> Vec<SolveStep> {
+    let strategy = Strategy::UniqueRectangle;
+    let mut steps = vec![];
+
+    // Type 1
+    if let ([_, _, _], [(roof, _)]) = (floors, roofs) {
+        steps.push(
+            SolveStep::new(strategy).with_eliminations(pair.iter().map(|f| ((*roof).clone(), f))),
+        );
+        return steps;
+    }
+
+    let [(first, first_numbers), (second, second_numbers)] = roofs else {
+        return steps;
+    };
+    let extra = |numbers: &SudokuNumbers| numbers.difference(pair);
+    let (first_extra, second_extra) = (extra(first_numbers), extra(second_numbers));
+
+    // Type 2
+    if let Some(extra) = first_extra.single().filter(|_| first_extra == second_extra) {
+        steps.push(
+            SolveStep::new(strategy).with_eliminations(common_peers_having(
+                board,
+                &[first, second],
+                extra,
+            )),
+        );
+    }
+
+    for container_type in ContainerType::ALL {
+        let container_number = first.container_number(container_type);
+        if second.container_number(container_type) != container_number {
+            continue;
+        }
+
+        let others = board
+            .iter_container(container_type, container_number)
+            .filter(|f| f.index() != *first && f.index() != *second)
+            .filter_map(|f| f.status.as_possibilities().map(|p| (f.index(), &p.numbers)))
+            .collect::<Vec<_>>();
+
+        // Type 3
+        let roof_extra = first_extra | second_extra;
+        if roof_extra.count_numbers() > 1 {
+            for size in 1..=3 {
+                for subset in combinations(&others, size) {
+                    let numbers = subset.iter().fold(roof_extra, |acc, (_, f)| acc | **f);
+                    if numbers.count_numbers() != size + 1 {
+                        continue;
+                    }
+
+                    let eliminations = others
+                        .iter()
+                        .filter(|(index, _)| !subset.iter().any(|(f, _)| f == index))
+                        .flat_map(|(index, others)| {
+                            (numbers & **others).iter().map(|f| ((*index).clone(), f))
+                        })
+                        .collect::<Vec<_>>();
+
+                    steps.push(
+                        SolveStep::new(strategy)
+                            .with_container(container_type, container_number)
+                            .with_sources(subset.iter().flat_map(|(index, numbers)| {
+                                numbers.iter().map(|f| ((*index).clone(), f))
+                            }))
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        // Type 4
+        for (only, removed) in [
+            (pair.iter().next(), pair.iter().last()),
+            (pair.iter().last(), pair.iter().next()),
+        ] {
+            let (Some(only), Some(removed)) = (only, removed) else {
+                continue;
+            };
+
+            if others.iter().all(|(_, f)| !f.has_number(only)) {
+                steps.push(
+                    SolveStep::new(strategy)
+                        .with_container(container_type, container_number)
+                        .with_eliminations([
+                            ((*first).clone(), removed),
+                            ((*second).clone(), removed),
+                        ]),
+                );
+            }
+        }
+    }
+
+    steps.retain(|f| !f.is_empty());
+    steps
+}
+
+/// Every unsolved block has two possible numbers, and every possible number is in each
+/// container exactly twice, except one block having three. That's a deadly pattern unless the
+/// block is the number that's possible three times in its containers.
+pub struct BivalueUniversalGraveStrategy;
+
+impl SudokuSolvingStrategy for BivalueUniversalGraveStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::BivalueUniversalGrave
+    }
+
+    fn requires_uniqueness(&self) -> bool {
+        true
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let blocks = possible_blocks(board);
+        let mut others = blocks.iter().filter(|(_, f)| f.count_numbers() != 2);
+        let (Some((index, numbers)), None) = (others.next(), others.next()) else {
+            return vec![];
+        };
+        if numbers.count_numbers() != 3 {
+            return vec![];
+        }
+
+        let count = |container_type: ContainerType, container_number, number| {
+            board
+                .iter_container(container_type, container_number)
+                .filter(|f| {
+                    f.status
+                        .as_possibilities()
+                        .is_some_and(|p| p.numbers.has_number(number))
+                })
+                .count()
+        };
+
+        let Some(number) = numbers
+            .iter()
+            .find(|f| count(ContainerType::Row, index.row, *f) == 3)
+        else {
+            return vec![];
+        };
+
+        for container_type in ContainerType::ALL {
+            for container_number in SudokuNumber::ALL {
+                for other in SudokuNumber::ALL {
+                    let expected = if other == number
+                        && index.container_number(container_type) == container_number
+                    {
+                        3
+                    } else {
+                        2
+                    };
+
+                    if ![0, expected].contains(&count(container_type, container_number, other)) {
+                        return vec![];
+                    }
+                }
+            }
+        }
+
+        vec![
+            SolveStep::new(self.strategy())
+                .with_sources(numbers.iter().map(|f| ((*index).clone(), f)))
+                .with_placement((*index).clone(), number)
+                .with_eliminations(peers_having(board, index, number)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Possibilities, SudokuBlockStatus,
+        strategies::{pipeline::StrategyRegistry, test_boards::board_with_blocks},
+    };
+
+    use super::*;
+    use SudokuNumber::*;
+
+    /// A rectangle on rows 1, 2 and columns 1, 4 with `{1, 2}` floors on row 1.
+    fn rectangle_with(
+        first_roof: &[SudokuNumber],
+        second_roof: &[SudokuNumber],
+        others: &[(BlockIndex, &[SudokuNumber])],
+    ) -> SudokuBoard {
+        let mut blocks = vec![
+            (BlockIndex::new(One, One), &[One, Two][..]),
+            (BlockIndex::new(One, Four), &[One, Two][..]),
+            (BlockIndex::new(Two, One), first_roof),
+            (BlockIndex::new(Two, Four), second_roof),
+        ];
+        blocks.extend(others.iter().cloned());
+        board_with_blocks(&blocks)
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_1() {
+        let board = rectangle_with(&[One, Two], &[One, Two, Three], &[]);
+
+        let steps = UniqueRectangleStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].source_blocks(),
+            vec![
+                BlockIndex::new(One, One),
+                BlockIndex::new(One, Four),
+                BlockIndex::new(Two, One),
+                BlockIndex::new(Two, Four)
+            ]
+        );
+        assert_eq!(
+            steps[0].eliminations,
+            vec![
+                (BlockIndex::new(Two, Four), One),
+                (BlockIndex::new(Two, Four), Two)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_2() {
+        let board = rectangle_with(
+            &[One, Two, Three],
+            &[One, Two, Three],
+            &[(BlockIndex::new(Two, Nine), &[One, Two, Three])],
+        );
+
+        let steps = UniqueRectangleStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].eliminations,
+            vec![(BlockIndex::new(Two, Nine), Three)]
+        );
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_3() {
+        // The roof extras {5, 6} form a naked pair with r2c9.
+        let board = rectangle_with(
+            &[One, Two, Five],
+            &[One, Two, Six],
+            &[
+                (BlockIndex::new(Two, Eight), &[One, Two, Seven]),
+                (BlockIndex::new(Two, Nine), &[Five, Six]),
+            ],
+        );
+
+        let steps = UniqueRectangleStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].container, Some((ContainerType::Row, Two)));
+        assert_eq!(steps[0].eliminations.len(), 10);
+        assert!(
+            steps[0]
+                .eliminations
+                .iter()
+                .all(|(index, number)| index.row == Two
+                    && ![One, Four, Eight, Nine].contains(&index.col)
+                    && [Five, Six].contains(number))
+        );
+    }
+
+    #[test]
+    fn test_unique_rectangle_type_4() {
+        // 1 in row 2 is only possible in the roof.
+        let board = rectangle_with(
+            &[One, Two, Five],
+            &[One, Two, Six],
+            &[(BlockIndex::new(Two, Nine), &[Two, Seven])],
+        );
+
+        let steps = UniqueRectangleStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].eliminations,
+            vec![
+                (BlockIndex::new(Two, One), Two),
+                (BlockIndex::new(Two, Four), Two)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_requires_uniqueness() {
+        let board = rectangle_with(&[One, Two], &[One, Two, Three], &[]);
+        let pipeline = StrategyRegistry::default().pipeline([Strategy::UniqueRectangle]);
+
+        assert!(pipeline.clone().step(&mut board.clone()).is_none());
+        assert!(
+            pipeline
+                .assuming_unique(true)
+                .step(&mut board.clone())
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_bivalue_universal_grave() {
+        // Blocks that are 1 or 2 in the solution can be both, same for 3 and 4.
+        let mut board = SudokuBoard::from_u8(sudoku_samples::easy::FIRST)
+            .solution()
+            .unwrap();
+        for block in board.get_blocks_mut() {
+            let numbers = match block.status.as_resolved().or(block.status.as_fixed()) {
+                Some(One | Two) => SudokuNumbers::new([One, Two]),
+                Some(Three | Four) => SudokuNumbers::new([Three, Four]),
+                _ => continue,
+            };
+            block.status = SudokuBlockStatus::Possibilities(Possibilities::new(numbers));
+        }
+        assert!(BivalueUniversalGraveStrategy.find_steps(&board).is_empty());
+
+        let index = board
+            .get_blocks()
+            .find(|f| {
+                f.status
+                    .as_possibilities()
+                    .is_some_and(|p| p.numbers.has_number(One))
+            })
+            .unwrap()
+            .index()
+            .clone();
+        board
+            .get_block_mut(&index)
+            .status
+            .as_possibilities_mut()
+            .unwrap()
+            .numbers
+            .set_number(Three);
+
+        let steps = BivalueUniversalGraveStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].placement, Some((index.clone(), Three)));
+        assert!(!steps[0].eliminations.is_empty());
+
+        board.engage_strategy(&BivalueUniversalGraveStrategy, false);
+        assert_eq!(board.get_block(&index).status.as_resolved(), Some(&Three));
+        assert!(board.iter_peers(&index).all(|f| {
+            f.status
+                .as_possibilities()
+                .is_none_or(|p| !p.numbers.has_number(Three))
+        }));
+    }
+}
@@ -0,0 +1,289 @@
+//! Wing strategies, a pivot block and two pincer blocks that see it. Whatever the pivot is,
+//! one of the pincers has to be the shared number, so it's removed from blocks seeing both pincers.
+
+use crate::{
+    ContainerType, SudokuBoard,
+    strategies::{
+        SolveStep, Strategy, SudokuSolvingStrategy, combinations, common_peers_having,
+        conjugate_pairs, possible_blocks,
+    },
+};
+
+/// Pivot `{x, y}` seeing pincers `{x, z}` and `{y, z}`, removes `z` from blocks seeing both pincers.
+pub struct XYWingStrategy;
+
+impl SudokuSolvingStrategy for XYWingStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::XYWing
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+        let blocks = possible_blocks(board);
+        let bivalues = blocks
+            .iter()
+            .filter(|(_, numbers)| numbers.count_numbers() == 2)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for (pivot, numbers) in &bivalues {
+            let mut pivot_numbers = numbers.iter();
+            let (Some(x), Some(y)) = (pivot_numbers.next(), pivot_numbers.next()) else {
+                continue;
+            };
+
+            for (x_pincer, x_numbers) in bivalues.iter().filter(|(f, _)| f.is_peer_of(pivot)) {
+                let Some(z) = x_numbers.iter().find(|f| *f != x) else {
+                    continue;
+                };
+                if !x_numbers.has_number(x) || z == y {
+                    continue;
+                }
+
+                for (y_pincer, _) in bivalues.iter().filter(|(f, numbers)| {
+                    f.is_peer_of(pivot)
+                        && f != x_pincer
+                        && numbers.has_number(y)
+                        && numbers.has_number(z)
+                }) {
+                    let eliminations = common_peers_having(board, &[x_pincer, y_pincer], z);
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_sources([
+                                ((*pivot).clone(), x),
+                                ((*pivot).clone(), y),
+                                ((*x_pincer).clone(), x),
+                                ((*x_pincer).clone(), z),
+                                ((*y_pincer).clone(), y),
+                                ((*y_pincer).clone(), z),
+                            ])
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+/// Pivot `{x, y, z}` seeing pincers `{x, z}` and `{y, z}`, removes `z` from blocks seeing
+/// the pivot and both pincers.
+pub struct XYZWingStrategy;
+
+impl SudokuSolvingStrategy for XYZWingStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::XYZWing
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+        let blocks = possible_blocks(board);
+
+        for (pivot, numbers) in blocks.iter().filter(|(_, f)| f.count_numbers() == 3) {
+            let pincers = blocks
+                .iter()
+                .filter(|(index, pincer)| {
+                    index.is_peer_of(pivot)
+                        && pincer.count_numbers() == 2
+                        && pincer.iter().all(|f| numbers.has_number(f))
+                })
+                .copied()
+                .collect::<Vec<_>>();
+
+            for pair in combinations(&pincers, 2) {
+                let [(first, first_numbers), (second, second_numbers)] = pair[..] else {
+                    continue;
+                };
+                if first_numbers == second_numbers {
+                    continue;
+                }
+
+                let Some(z) = first_numbers.iter().find(|f| second_numbers.has_number(*f)) else {
+                    continue;
+                };
+
+                let eliminations = common_peers_having(board, &[pivot, first, second], z);
+                if eliminations.is_empty() {
+                    continue;
+                }
+
+                steps.push(
+                    SolveStep::new(self.strategy())
+                        .with_sources(numbers.iter().map(|f| ((*pivot).clone(), f)))
+                        .with_sources(first_numbers.iter().map(|f| (first.clone(), f)))
+                        .with_sources(second_numbers.iter().map(|f| (second.clone(), f)))
+                        .with_eliminations(eliminations),
+                );
+            }
+        }
+
+        steps
+    }
+}
+
+/// Two blocks `{x, y}` that don't see each other, connected by a strong link on `x`
+/// (a container where `x` is only possible in two blocks, each seeing one of them).
+/// One of them has to be `y`, so it's removed from blocks seeing both.
+pub struct WWingStrategy;
+
+impl SudokuSolvingStrategy for WWingStrategy {
+    fn strategy(&self) -> Strategy {
+        Strategy::WWing
+    }
+
+    fn find_steps(&self, board: &SudokuBoard) -> Vec<SolveStep> {
+        let mut steps = vec![];
+        let blocks = possible_blocks(board);
+        let bivalues = blocks
+            .iter()
+            .filter(|(_, numbers)| numbers.count_numbers() == 2)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for pair in combinations(&bivalues, 2) {
+            let [(first, numbers), (second, second_numbers)] = pair[..] else {
+                continue;
+            };
+            if numbers != second_numbers || first.is_peer_of(second) {
+                continue;
+            }
+
+            for (x, y) in [
+                (numbers.iter().next(), numbers.iter().last()),
+                (numbers.iter().last(), numbers.iter().next()),
+            ] {
+                let (Some(x), Some(y)) = (x, y) else {
+                    continue;
+                };
+
+                for pair in ContainerType::ALL
+                    .into_iter()
+                    .flat_map(|f| conjugate_pairs(board, f, x))
+                {
+                    let [start, end] = &pair.blocks;
+                    if [start, end].iter().any(|f| *f == first || *f == second) {
+                        continue;
+                    }
+
+                    let linked = (start.is_peer_of(first) && end.is_peer_of(second))
+                        || (start.is_peer_of(second) && end.is_peer_of(first));
+                    if !linked {
+                        continue;
+                    }
+
+                    let eliminations = common_peers_having(board, &[first, second], y);
+                    if eliminations.is_empty() {
+                        continue;
+                    }
+
+                    steps.push(
+                        SolveStep::new(self.strategy())
+                            .with_container(pair.container.0, pair.container.1)
+                            .with_sources([
+                                (first.clone(), x),
+                                (first.clone(), y),
+                                (second.clone(), x),
+                                (second.clone(), y),
+                                (start.clone(), x),
+                                (end.clone(), x),
+                            ])
+                            .with_eliminations(eliminations),
+                    );
+                }
+            }
+        }
+
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BlockIndex, numbers::SudokuNumber, strategies::test_boards::board_with_blocks};
+
+    use super::*;
+    use SudokuNumber::*;
+
+    #[test]
+    fn test_xy_wing() {
+        let mut board = board_with_blocks(&[
+            (BlockIndex::new(One, One), &[One, Two]),
+            (BlockIndex::new(One, Five), &[One, Three]),
+            (BlockIndex::new(Two, Two), &[Two, Three]),
+            // Sees both pincers.
+            (BlockIndex::new(Two, Five), &[Three, Four]),
+            // Sees only one pincer.
+            (BlockIndex::new(Two, Nine), &[Three, Four]),
+        ]);
+
+        let steps = XYWingStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].source_blocks(),
+            vec![
+                BlockIndex::new(One, One),
+                BlockIndex::new(One, Five),
+                BlockIndex::new(Two, Two)
+            ]
+        );
+        assert_eq!(
+            steps[0].eliminations,
+            vec![(BlockIndex::new(Two, Five), Three)]
+        );
+
+        board.apply_strategy(&XYWingStrategy.outcome(&board));
+        assert!(XYWingStrategy.outcome(&board).is_empty());
+    }
+
+    #[test]
+    fn test_xyz_wing() {
+        let board = board_with_blocks(&[
+            (BlockIndex::new(One, One), &[One, Two, Three]),
+            (BlockIndex::new(One, Five), &[One, Three]),
+            (BlockIndex::new(Two, Two), &[Two, Three]),
+            // Sees the pivot and both pincers.
+            (BlockIndex::new(One, Two), &[Three, Four]),
+            // Sees both pincers, but not the pivot.
+            (BlockIndex::new(Two, Five), &[Three, Four]),
+        ]);
+
+        let steps = XYZWingStrategy.find_steps(&board);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].eliminations,
+            vec![(BlockIndex::new(One, Two), Three)]
+        );
+    }
+
+    #[test]
+    fn test_w_wing() {
+        let board = board_with_blocks(&[
+            (BlockIndex::new(One, One), &[One, Two]),
+            (BlockIndex::new(Five, Nine), &[One, Two]),
+            // Strong link on 1 in row 9.
+            (BlockIndex::new(Nine, One), &[One, Four]),
+            (BlockIndex::new(Nine, Nine), &[One, Five]),
+            // Sees both.
+            (BlockIndex::new(One, Nine), &[Two, Six]),
+            (BlockIndex::new(Five, One), &[Two, Six]),
+        ]);
+
+        let steps = WWingStrategy.find_steps(&board);
+        assert!(!steps.is_empty());
+        assert!(steps.iter().all(|f| f.eliminations
+            == vec![
+                (BlockIndex::new(One, Nine), Two),
+                (BlockIndex::new(Five, One), Two)
+            ]));
+        assert!(
+            steps
+                .iter()
+                .any(|f| f.container == Some((ContainerType::Row, Nine)))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Static tables of the blocks in every container and the peers of every block,
+//! so hot paths can walk them without allocating or scanning the whole board.
+
+use crate::{BlockIndex, ContainerType, numbers::SudokuNumber};
+
+/// Every block, by position (`row * 9 + col`).
+static INDEXES: [BlockIndex; 81] = build_indexes();
+
+/// Blocks of every container, rows first, then columns and squares.
+static CONTAINERS: [[BlockIndex; 9]; 27] = build_containers();
+
+/// Peers of every block by position, in the same order as the board.
+static PEERS: [[BlockIndex; 20]; 81] = build_peers();
+
+/// Every block index in the same order as the board.
+pub fn indexes() -> &'static [BlockIndex; 81] {
+    &INDEXES
+}
+
+/// Blocks of the container, in the same order as the board.
+pub fn container(container_type: ContainerType, number: SudokuNumber) -> &'static [BlockIndex; 9] {
+    let offset = match container_type {
+        ContainerType::Row => 0,
+        ContainerType::Column => 9,
+        ContainerType::Square => 18,
+    };
+    &CONTAINERS[offset + number.to_index()]
+}
+
+/// Blocks that share a row, column or square with the block, in the same order as the board.
+pub fn peers(index: &BlockIndex) -> &'static [BlockIndex; 20] {
+    &PEERS[index.position()]
+}
+
+const fn index_at(position: usize) -> BlockIndex {
+    BlockIndex {
+        row: SudokuNumber::ALL[position / 9],
+        col: SudokuNumber::ALL[position % 9],
+    }
+}
+
+const fn square_at(position: usize) -> usize {
+    (position / 27) * 3 + (position % 9) / 3
+}
+
+const fn build_indexes() -> [BlockIndex; 81] {
+    let mut indexes = [const { index_at(0) }; 81];
+    let mut position = 0;
+    while position < 81 {
+        indexes[position] = index_at(position);
+        position += 1;
+    }
+    indexes
+}
+
+const fn build_containers() -> [[BlockIndex; 9]; 27] {
+    let mut containers = [const { [const { index_at(0) }; 9] }; 27];
+    let mut number = 0;
+    while number < 9 {
+        let mut i = 0;
+        while i < 9 {
+            containers[number][i] = index_at(number * 9 + i);
+            containers[9 + number][i] = index_at(i * 9 + number);
+            containers[18 + number][i] =
+                index_at((number / 3 * 3 + i / 3) * 9 + number % 3 * 3 + i % 3);
+            i += 1;
+        }
+        number += 1;
+    }
+    containers
+}
+
+const fn build_peers() -> [[BlockIndex; 20]; 81] {
+    let mut peers = [const { [const { index_at(0) }; 20] }; 81];
+    let mut position = 0;
+    while position < 81 {
+        let mut count = 0;
+        let mut other = 0;
+        while other < 81 {
+            if other != position
+                && (other / 9 == position / 9
+                    || other % 9 == position % 9
+                    || square_at(other) == square_at(position))
+            {
+                peers[position][count] = index_at(other);
+                count += 1;
+            }
+            other += 1;
+        }
+        position += 1;
+    }
+    peers
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SudokuBoard;
+
+    use super::*;
+
+    #[test]
+    fn test_tables() {
+        assert!(
+            indexes()
+                .iter()
+                .eq(SudokuBoard::iter_block_indexes().collect::<Vec<_>>().iter())
+        );
+
+        let board = SudokuBoard::default();
+        for number in SudokuNumber::ALL {
+            let row = container(ContainerType::Row, number);
+            assert!(row.iter().eq(board.get_row(number).map(|f| f.index())));
+            let column = container(ContainerType::Column, number);
+            assert!(column.iter().eq(board.get_col(number).map(|f| f.index())));
+            let square = container(ContainerType::Square, number);
+            assert!(
+                square
+                    .iter()
+                    .eq(board.get_square(number).map(|f| f.index()))
+            );
+        }
+
+        for index in indexes() {
+            assert!(
+                peers(index)
+                    .iter()
+                    .eq(indexes().iter().filter(|f| f.is_peer_of(index)))
+            );
+        }
+    }
+}